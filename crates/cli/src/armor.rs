@@ -0,0 +1,184 @@
+//! ASCII-armored share blocks
+//!
+//! `--format armor` writes each share as a PEM-like block that survives being
+//! pasted into email or chat:
+//!
+//! ```text
+//! -----BEGIN FRACTUS SHARE-----
+//! Threshold: 2
+//! Set-Id: 0d23e1fcf40341f7b6cba070975af577
+//! Index: 1
+//! Created: 2026-10-18T15:26:14.937337348+00:00
+//!
+//! AV7rg7UUwOZmSvmD6xDjxKWTLSFh4Fxs
+//! =l/9U
+//! -----END FRACTUS SHARE-----
+//! ```
+//!
+//! The body is the base64-encoded share bytes, wrapped at 64 columns, followed
+//! by `=` and the base64-encoded CRC-24 of those bytes as in OpenPGP armor
+//! (RFC 4880, section 6.1). Headers are optional on input and unknown headers
+//! are ignored. Any number of blocks may be concatenated, and text around them
+//! is ignored.
+
+use anyhow::{Context, Result, anyhow, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use crate::formats::ShareData;
+
+pub const BEGIN: &str = "-----BEGIN FRACTUS SHARE-----";
+const END: &str = "-----END FRACTUS SHARE-----";
+const LINE_LEN: usize = 64;
+
+const CRC24_INIT: u32 = 0xB7_04CE;
+const CRC24_POLY: u32 = 0x186_4CFB;
+
+/// Writes a share as an armored block, ending in a newline
+pub fn encode(share_data: &ShareData) -> String {
+    let bytes = share_data.to_bytes();
+    let mut block = format!("{}\n", BEGIN);
+
+    let mut headers = Vec::new();
+    if let Some(threshold) = share_data.threshold {
+        headers.push(format!("Threshold: {}", threshold));
+    }
+    if let Some(set_id) = &share_data.set_id {
+        headers.push(format!("Set-Id: {}", set_id));
+    }
+    headers.push(format!("Index: {}", share_data.id.unwrap_or(share_data.x)));
+    if let Some(created_at) = &share_data.created_at {
+        headers.push(format!("Created: {}", created_at));
+    }
+    for header in headers {
+        block.push_str(&header);
+        block.push('\n');
+    }
+    block.push('\n');
+
+    let body = BASE64.encode(&bytes);
+    for line in body.as_bytes().chunks(LINE_LEN) {
+        block.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+        block.push('\n');
+    }
+    let checksum = crc24(&bytes).to_be_bytes();
+    block.push_str(&format!("={}\n", BASE64.encode(&checksum[1..])));
+    block.push_str(END);
+    block.push('\n');
+    block
+}
+
+/// Whether the content contains an armored share block
+pub fn is_armored(content: &str) -> bool {
+    content.contains(BEGIN)
+}
+
+/// Reads every armored share block in the content
+pub fn decode_all(content: &str) -> Result<Vec<ShareData>> {
+    let mut shares = Vec::new();
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in content.lines().map(str::trim) {
+        match (&mut block, line) {
+            (None, BEGIN) => block = Some(Vec::new()),
+            (None, _) => {}
+            (Some(_), BEGIN) => {
+                bail!("Armored share {} has no END line", shares.len() + 1)
+            }
+            (Some(lines), END) => {
+                let share = decode_block(lines)
+                    .map_err(|e| anyhow!("Armored share {}: {:#}", shares.len() + 1, e))?;
+                shares.push(share);
+                block = None;
+            }
+            (Some(lines), _) => lines.push(line),
+        }
+    }
+
+    if block.is_some() {
+        bail!("Armored share {} has no END line", shares.len() + 1);
+    }
+    if shares.is_empty() {
+        bail!("No armored shares found");
+    }
+    Ok(shares)
+}
+
+fn decode_block(lines: &[&str]) -> Result<ShareData> {
+    // Headers, if any, come first and end at a blank line; base64 has no ':'
+    let mut lines = lines.iter().copied().peekable();
+    let mut headers = Vec::new();
+    while let Some(line) = lines.next_if(|line| line.contains(':')) {
+        let (name, value) = line.split_once(':').expect("line contains ':'");
+        headers.push((name.trim(), value.trim()));
+    }
+
+    let mut body = String::new();
+    let mut checksum = None;
+    for line in lines.filter(|line| !line.is_empty()) {
+        if checksum.is_some() {
+            bail!("Unexpected data after the checksum");
+        }
+        match line.strip_prefix('=') {
+            Some(crc) => checksum = Some(crc),
+            None => body.push_str(line),
+        }
+    }
+
+    let bytes = BASE64.decode(&body).context("Invalid base64 encoding")?;
+    let Some(checksum) = checksum else {
+        bail!("Missing checksum");
+    };
+    let checksum = BASE64
+        .decode(checksum)
+        .context("Invalid checksum encoding")?;
+    if checksum.len() != 3 || checksum[..] != crc24(&bytes).to_be_bytes()[1..] {
+        bail!("Checksum mismatch; the share was altered or mistyped");
+    }
+
+    let mut share_data = ShareData::from_bytes(&bytes)?;
+    for (name, value) in headers {
+        match name {
+            "Threshold" => {
+                share_data.threshold = Some(value.parse().context("Invalid Threshold header")?)
+            }
+            "Set-Id" => share_data.set_id = Some(value.to_string()),
+            "Index" => share_data.id = Some(value.parse().context("Invalid Index header")?),
+            "Created" => share_data.created_at = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    Ok(share_data)
+}
+
+fn crc24(data: &[u8]) -> u32 {
+    let mut crc = CRC24_INIT;
+    for &byte in data {
+        crc ^= u32::from(byte) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x100_0000 != 0 {
+                crc ^= CRC24_POLY;
+            }
+        }
+    }
+    crc & 0xFF_FFFF
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc24_matches_doc_example() {
+        let bytes = BASE64.decode("AV7rg7UUwOZmSvmD6xDjxKWTLSFh4Fxs").unwrap();
+        let checksum = crc24(&bytes).to_be_bytes();
+        assert_eq!(BASE64.encode(&checksum[1..]), "l/9U");
+    }
+
+    #[test]
+    fn test_crc24_check_value() {
+        // The standard check value of CRC-24/OPENPGP
+        assert_eq!(crc24(b"123456789"), 0x21_CF02);
+    }
+}
@@ -0,0 +1,198 @@
+//! Bech32m share strings
+//!
+//! `--format bech32m` writes a share's bytes as a single Bech32m string
+//! (BIP-350) with the human-readable prefix `fractus`:
+//!
+//! ```text
+//! fractus1qypq30u0jpgd5ugmhtsdcsct9jmrdu2y2ggh236s
+//! ```
+//!
+//! Unlike hex and base64 the encoding cannot be mistaken for anything else,
+//! its checksum catches up to four mistyped characters in strings of up to 89
+//! characters and almost any mistake in longer ones, and it may be written in
+//! either case. The upper-case form uses only characters of the QR alphanumeric
+//! mode, which makes for compact QR codes. A string is at most 1023 characters,
+//! enough for secrets of up to 625 bytes.
+
+use anyhow::{Result, anyhow, bail};
+use bech32::primitives::decode::{CheckedHrpstring, UncheckedHrpstring};
+use bech32::{Bech32m, Hrp};
+
+pub const HRP: &str = "fractus";
+const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const CHECKSUM_LEN: usize = 6;
+
+/// Encodes share bytes as a lower-case Bech32m string
+pub fn encode(bytes: &[u8]) -> Result<String> {
+    let hrp = Hrp::parse_unchecked(HRP);
+    bech32::encode::<Bech32m>(hrp, bytes).map_err(|_| {
+        anyhow!(
+            "A {}-byte share is too long for Bech32m; use another format",
+            bytes.len()
+        )
+    })
+}
+
+/// Whether the content looks like a Bech32m share string, in either case
+pub fn is_bech32m(content: &str) -> bool {
+    content
+        .trim_start()
+        .get(..HRP.len() + 1)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&format!("{}1", HRP)))
+}
+
+/// Decodes a Bech32m share string, naming the position of a mistyped
+/// character when it can be found
+pub fn decode(content: &str) -> Result<Vec<u8>> {
+    let s: String = content
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    let prefix = format!("{}1", HRP);
+    let Some(data) = s.strip_prefix(&prefix) else {
+        bail!("Not a Bech32m share: expected the prefix '{}'", prefix);
+    };
+    if let Some((i, c)) = data
+        .chars()
+        .enumerate()
+        .find(|&(_, c)| !CHARSET.contains(c))
+    {
+        bail!(
+            "Invalid character '{}' at position {} (Bech32m never uses 1, b, i or o)",
+            c,
+            prefix.len() + i + 1
+        );
+    }
+    if data.len() < CHECKSUM_LEN {
+        bail!("Bech32m share is too short");
+    }
+
+    match CheckedHrpstring::new::<Bech32m>(&s) {
+        Ok(checked) => Ok(checked.byte_iter().collect()),
+        Err(_) => match locate_error(&s, prefix.len()) {
+            Some((position, expected)) => bail!(
+                "Bech32m checksum mismatch: character {} ('{}') looks mistyped, probably '{}'",
+                position,
+                &s[position - 1..position],
+                expected
+            ),
+            None => bail!("Bech32m checksum mismatch: the share has several mistyped characters"),
+        },
+    }
+}
+
+/// Finds the single substitution that repairs the checksum, as a 1-based
+/// position and the character that belongs there
+fn locate_error(s: &str, data_start: usize) -> Option<(usize, char)> {
+    let mut candidate = s.as_bytes().to_vec();
+    let mut found = None;
+    for i in data_start..s.len() {
+        let original = candidate[i];
+        for c in CHARSET.bytes().filter(|&c| c != original) {
+            candidate[i] = c;
+            let fixed = std::str::from_utf8(&candidate).expect("charset is ASCII");
+            if UncheckedHrpstring::new(fixed).is_ok_and(|u| u.has_valid_checksum::<Bech32m>()) {
+                if found.is_some() {
+                    return None;
+                }
+                found = Some((i + 1, c as char));
+            }
+        }
+        candidate[i] = original;
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BYTES: [u8; 12] = [
+        1, 0x5e, 0xeb, 0x83, 0xb5, 0x14, 0xc0, 0xe6, 0x66, 0x4a, 0xf9, 0x83,
+    ];
+
+    /// Replaces the character at a 1-based position
+    fn substitute(s: &str, position: usize, c: char) -> String {
+        let mut chars: Vec<char> = s.chars().collect();
+        chars[position - 1] = c;
+        chars.into_iter().collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let encoded = encode(&BYTES).unwrap();
+        assert!(encoded.starts_with("fractus1"));
+        assert_eq!(decode(&encoded).unwrap(), BYTES);
+    }
+
+    #[test]
+    fn test_decode_ignores_case_and_whitespace() {
+        let encoded = encode(&BYTES).unwrap();
+        assert_eq!(decode(&encoded.to_ascii_uppercase()).unwrap(), BYTES);
+        let (head, tail) = encoded.split_at(15);
+        assert_eq!(decode(&format!(" {}\n{} ", head, tail)).unwrap(), BYTES);
+    }
+
+    #[test]
+    fn test_single_substitution_is_located() {
+        let encoded = encode(&BYTES).unwrap();
+        // Every data and checksum position, from the first after the prefix
+        for position in HRP.len() + 2..=encoded.len() {
+            let original = encoded.chars().nth(position - 1).unwrap();
+            let typo = CHARSET.chars().find(|&c| c != original).unwrap();
+            let error = decode(&substitute(&encoded, position, typo))
+                .unwrap_err()
+                .to_string();
+            assert_eq!(
+                error,
+                format!(
+                    "Bech32m checksum mismatch: character {} ('{}') looks mistyped, probably '{}'",
+                    position, typo, original
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_several_substitutions_are_not_located() {
+        let encoded = encode(&BYTES).unwrap();
+        let mut typo = encoded.clone();
+        for position in [10, 20] {
+            let original = typo.chars().nth(position - 1).unwrap();
+            let c = CHARSET.chars().find(|&c| c != original).unwrap();
+            typo = substitute(&typo, position, c);
+        }
+        let error = decode(&typo).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "Bech32m checksum mismatch: the share has several mistyped characters"
+        );
+    }
+
+    #[test]
+    fn test_forbidden_characters_are_named() {
+        let encoded = encode(&BYTES).unwrap();
+        for c in ['b', 'i', 'o', '1'] {
+            let error = decode(&substitute(&encoded, 12, c))
+                .unwrap_err()
+                .to_string();
+            assert_eq!(
+                error,
+                format!(
+                    "Invalid character '{}' at position 12 (Bech32m never uses 1, b, i or o)",
+                    c
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_other_prefix_is_rejected() {
+        let error = decode("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "Not a Bech32m share: expected the prefix 'fractus1'");
+    }
+}
@@ -0,0 +1,266 @@
+//! Dkg command implementation
+//!
+//! Runs one participant's side of a dealer-free joint secret generation. Each
+//! participant first runs `dkg deal` and hands every message file to the
+//! participant it is addressed to, then runs `dkg combine` on the messages it
+//! received to obtain its share of the joint secret.
+
+use anyhow::{Context, Result, bail};
+use clap::{Args, Subcommand};
+use fractus_shamir::Share;
+use fractus_shamir::dkg::{Deal, Dkg};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::formats::{OutputFormat, ShareData};
+
+#[derive(Args)]
+pub struct DkgCommand {
+    #[command(subcommand)]
+    pub step: DkgStep,
+}
+
+#[derive(Subcommand)]
+pub enum DkgStep {
+    /// Draw a random contribution and write one message per participant
+    Deal(DealArgs),
+
+    /// Combine the messages addressed to this participant into a share
+    Combine(CombineArgs),
+}
+
+#[derive(Args)]
+pub struct DealArgs {
+    #[command(flatten)]
+    pub params: DkgParams,
+
+    /// Length of the joint secret in bytes
+    #[arg(short, long, value_name = "BYTES", default_value = "32")]
+    pub length: usize,
+
+    /// Output directory for message files
+    #[arg(short, long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct CombineArgs {
+    #[command(flatten)]
+    pub params: DkgParams,
+
+    /// Message files or directories; messages for other participants are ignored
+    #[arg(value_name = "MESSAGES", required = true)]
+    pub inputs: Vec<PathBuf>,
+
+    /// Output file for the share
+    #[arg(short, long, value_name = "FILE")]
+    pub output: PathBuf,
+
+    /// Output format
+    #[arg(short = 'f', long, value_enum, default_value = "json")]
+    pub format: OutputFormat,
+
+    /// Include metadata in output
+    #[arg(long)]
+    pub include_metadata: bool,
+}
+
+#[derive(Args)]
+pub struct DkgParams {
+    /// This participant's id, from 1 to the number of participants
+    #[arg(long, value_name = "ID")]
+    pub id: u8,
+
+    /// Number of participants
+    #[arg(short = 'n', long, value_name = "COUNT")]
+    pub parties: u8,
+
+    /// Minimum threshold of shares required for recovery
+    #[arg(short = 'k', long, value_name = "THRESHOLD")]
+    pub threshold: u8,
+}
+
+/// A sub-share in transit from one participant to another.
+#[derive(Serialize, Deserialize)]
+struct Message {
+    from: u8,
+    to: u8,
+    threshold: u8,
+    parties: u8,
+    /// Hex-encoded sub-share
+    share: String,
+}
+
+impl DkgParams {
+    fn dkg(&self) -> Result<Dkg> {
+        let dkg = Dkg::new(self.threshold, self.parties).with_context(|| {
+            format!(
+                "Invalid parameters: threshold {} with {} participants",
+                self.threshold, self.parties
+            )
+        })?;
+        if self.id == 0 || self.id > self.parties {
+            bail!(
+                "Participant id must be between 1 and {}, got {}",
+                self.parties,
+                self.id
+            );
+        }
+        Ok(dkg)
+    }
+}
+
+impl DkgCommand {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        match &self.step {
+            DkgStep::Deal(args) => args.execute(config),
+            DkgStep::Combine(args) => args.execute(config),
+        }
+    }
+}
+
+impl DealArgs {
+    fn execute(&self, _config: &Config) -> Result<()> {
+        let dkg = self.params.dkg()?;
+        if self.length == 0 {
+            bail!("Secret length must be at least 1 byte");
+        }
+
+        let deals = dkg
+            .deal(self.params.id, self.length, &mut rand::thread_rng())
+            .context("Failed to deal the contribution")?;
+
+        let output_dir = self
+            .output_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."));
+        if !output_dir.exists() {
+            fs::create_dir_all(&output_dir)
+                .with_context(|| format!("Failed to create directory: {}", output_dir.display()))?;
+        }
+
+        for deal in &deals {
+            let message = Message {
+                from: deal.from,
+                to: deal.to,
+                threshold: dkg.threshold(),
+                parties: dkg.parties(),
+                share: hex::encode(deal.share.to_bytes()),
+            };
+            let filepath = output_dir.join(format!("dkg-{:03}-to-{:03}.json", deal.from, deal.to));
+            fs::write(&filepath, serde_json::to_string_pretty(&message)?)
+                .with_context(|| format!("Failed to write {}", filepath.display()))?;
+        }
+
+        println!(
+            "✅ Wrote {} messages from participant {}",
+            deals.len(),
+            self.params.id
+        );
+        println!(
+            "Send each message only to the participant it is addressed to, over a private channel"
+        );
+        if let Some(dir) = &self.output_dir {
+            println!("Messages saved to: {}", dir.display());
+        }
+
+        Ok(())
+    }
+}
+
+impl CombineArgs {
+    fn execute(&self, _config: &Config) -> Result<()> {
+        let dkg = self.params.dkg()?;
+        let mut participant = dkg.participant(self.params.id)?;
+
+        for path in self.message_files()? {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let message: Message = serde_json::from_str(&content)
+                .with_context(|| format!("{} is not a dkg message", path.display()))?;
+            if message.to != self.params.id {
+                continue;
+            }
+            if message.threshold != dkg.threshold() || message.parties != dkg.parties() {
+                bail!(
+                    "{} was dealt for threshold {} with {} participants",
+                    path.display(),
+                    message.threshold,
+                    message.parties
+                );
+            }
+
+            let bytes = hex::decode(&message.share)
+                .with_context(|| format!("Invalid share encoding in {}", path.display()))?;
+            let share = Share::from_bytes(&bytes)
+                .map_err(|e| anyhow::anyhow!("Invalid share in {}: {}", path.display(), e))?;
+            participant
+                .receive(Deal {
+                    from: message.from,
+                    to: message.to,
+                    share,
+                })
+                .with_context(|| format!("Rejected {}", path.display()))?;
+        }
+
+        let missing = participant.missing();
+        if !missing.is_empty() {
+            let missing: Vec<String> = missing.iter().map(u8::to_string).collect();
+            bail!("Missing messages from participants: {}", missing.join(", "));
+        }
+
+        let share = participant
+            .finalize()
+            .context("Failed to combine the messages")?;
+        let share_data = ShareData::new(
+            share,
+            self.params.id as usize,
+            dkg.parties(),
+            dkg.threshold(),
+            self.include_metadata,
+        );
+        share_data.write(self.format, &self.output)?;
+
+        println!(
+            "✅ Combined {} messages into the share of participant {}",
+            dkg.parties(),
+            self.params.id
+        );
+        println!("Share saved to: {}", self.output.display());
+        println!("The received messages are no longer needed and should be deleted");
+
+        Ok(())
+    }
+
+    fn message_files(&self) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for input in &self.inputs {
+            if input.is_dir() {
+                let mut entries = Vec::new();
+                for entry in fs::read_dir(input)
+                    .with_context(|| format!("Failed to read directory: {}", input.display()))?
+                {
+                    let path = entry?.path();
+                    if is_message_file(&path) {
+                        entries.push(path);
+                    }
+                }
+                entries.sort();
+                files.extend(entries);
+            } else {
+                files.push(input.clone());
+            }
+        }
+        Ok(files)
+    }
+}
+
+fn is_message_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("dkg-") && name.ends_with(".json"))
+}
@@ -2,12 +2,19 @@
 
 use anyhow::{Context, Result};
 use clap::Args;
+use ed25519_dalek::VerifyingKey;
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
 
+use crate::armor;
 use crate::config::Config;
+use crate::encryption::{Decryptor, EncryptedShare};
 use crate::formats::{InputFormat, ShareData};
+use crate::qr;
+use crate::signing::{self, SignatureStatus};
+use crate::transcript;
 
 #[derive(Args)]
 pub struct InfoCommand {
@@ -26,6 +33,16 @@ pub struct InfoCommand {
     /// Output format for information
     #[arg(long, value_enum, default_value = "table")]
     pub output_format: InfoOutputFormat,
+
+    /// Check share signatures against this dealer public key (hex, or a .pub
+    /// file written by `fractus keygen --signing`)
+    #[arg(long, value_name = "PUBKEY")]
+    pub trusted_dealer: Option<String>,
+
+    /// Try the passphrases in this file (one per line) on encrypted shares
+    /// instead of prompting
+    #[arg(long, value_name = "FILE")]
+    pub share_passphrases: Option<PathBuf>,
 }
 
 #[derive(clap::ValueEnum, Clone)]
@@ -42,6 +59,12 @@ struct ShareInfo {
     y_length: usize,
     threshold: Option<u8>,
     total_shares: Option<u8>,
+    set_id: Option<String>,
+    /// Fingerprint of the share bytes, as printed on paper sheets
+    fingerprint: Option<String>,
+    signature: Option<SignatureStatus>,
+    /// "decrypted" or "locked" for encrypted share files
+    encryption: Option<&'static str>,
     format: String,
     file_path: Option<PathBuf>,
 }
@@ -58,24 +81,36 @@ struct ShareSetInfo {
 
 impl InfoCommand {
     pub fn execute(&self, _config: &Config) -> Result<()> {
-        let mut all_shares = Vec::new();
+        let trusted_dealer = self
+            .trusted_dealer
+            .as_deref()
+            .map(signing::parse_verifying_key)
+            .transpose()?;
+
+        // Without a passphrase file or a terminal to prompt on, encrypted
+        // shares are shown locked
+        let decryptor = if self.share_passphrases.is_some() || io::stdin().is_terminal() {
+            Some(Decryptor::new(self.share_passphrases.as_deref())?)
+        } else {
+            None
+        };
         let mut share_infos = Vec::new();
 
         // Collect all shares and their metadata
         for input in &self.inputs {
             if input.is_dir() {
-                let (shares, infos) = self.analyze_directory(input)?;
-                all_shares.extend(shares);
+                let infos =
+                    self.analyze_directory(input, trusted_dealer.as_ref(), decryptor.as_ref())?;
                 share_infos.extend(infos);
             } else {
-                let (share, info) = self.analyze_file(input)?;
-                all_shares.push(share);
-                share_infos.push(info);
+                let infos =
+                    self.analyze_file(input, trusted_dealer.as_ref(), decryptor.as_ref())?;
+                share_infos.extend(infos);
             }
         }
 
         // Analyze the complete set
-        let set_info = self.analyze_share_set(all_shares, share_infos);
+        let set_info = self.analyze_share_set(share_infos);
 
         // Output the information
         self.output_info(&set_info)?;
@@ -86,8 +121,9 @@ impl InfoCommand {
     fn analyze_directory(
         &self,
         dir: &PathBuf,
-    ) -> Result<(Vec<fractus_shamir::Share>, Vec<ShareInfo>)> {
-        let mut shares = Vec::new();
+        trusted_dealer: Option<&VerifyingKey>,
+        decryptor: Option<&Decryptor>,
+    ) -> Result<Vec<ShareInfo>> {
         let mut infos = Vec::new();
 
         let entries = fs::read_dir(dir)
@@ -97,63 +133,139 @@ impl InfoCommand {
             let entry = entry?;
             let path = entry.path();
 
-            if path.is_file() {
-                if let Ok((share, info)) = self.analyze_file(&path) {
-                    shares.push(share);
-                    infos.push(info);
-                }
+            if !path.is_file() {
+                continue;
+            }
+            match self.analyze_file(&path, trusted_dealer, decryptor) {
+                Ok(file_infos) => infos.extend(file_infos),
+                Err(e) => log::warn!("Skipping {}: {:#}", path.display(), e),
             }
         }
 
-        Ok((shares, infos))
+        Ok(infos)
     }
 
-    fn analyze_file(&self, path: &PathBuf) -> Result<(fractus_shamir::Share, ShareInfo)> {
+    fn analyze_file(
+        &self,
+        path: &PathBuf,
+        trusted_dealer: Option<&VerifyingKey>,
+        decryptor: Option<&Decryptor>,
+    ) -> Result<Vec<ShareInfo>> {
         let format = if let Some(f) = &self.format {
             *f
         } else {
             InputFormat::detect_from_path(path)?
         };
 
-        let content = fs::read_to_string(path)
-            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        // Binary shares and QR images are not text
+        let bytes =
+            fs::read(path).with_context(|| format!("Failed to read file: {}", path.display()))?;
+        let content = String::from_utf8_lossy(&bytes);
+
+        if let Some(encrypted) = EncryptedShare::parse(&content) {
+            let info = self.analyze_encrypted(&encrypted, path, trusted_dealer, decryptor)?;
+            return Ok(vec![info]);
+        }
 
-        let share_data = match format {
+        // Armored files, QR images and transcripts may hold several shares
+        let shares = match format {
             InputFormat::Json => {
-                serde_json::from_str::<ShareData>(&content).context("Failed to parse JSON")?
-            }
-            InputFormat::Hex => ShareData::from_hex(&content)?,
-            InputFormat::Base64 => ShareData::from_base64(&content)?,
-            InputFormat::Binary => {
-                let bytes = fs::read(path)?;
-                ShareData::from_bytes(&bytes)?
+                vec![serde_json::from_str::<ShareData>(&content).context("Failed to parse JSON")?]
             }
+            InputFormat::Hex => vec![ShareData::from_hex(&content)?],
+            InputFormat::Base64 => vec![ShareData::from_base64(&content)?],
+            InputFormat::Bech32m => vec![ShareData::from_bech32m(&content)?],
+            InputFormat::Binary => vec![ShareData::from_bytes(&bytes)?],
+            InputFormat::Armor => armor::decode_all(&content)?,
+            InputFormat::Qr => qr::decode_image(path)?,
+            InputFormat::Transcript => transcript::decode_all(&content)?,
         };
 
-        let share = share_data.clone().into_share();
+        Ok(shares
+            .into_iter()
+            .map(|share_data| {
+                Self::share_info(
+                    share_data,
+                    trusted_dealer,
+                    None,
+                    format!("{:?}", format),
+                    path,
+                )
+            })
+            .collect())
+    }
+
+    /// Decrypts an encrypted share if possible, falling back to the
+    /// metadata stored in the clear, also when the passphrase prompt cannot
+    /// be read
+    fn analyze_encrypted(
+        &self,
+        encrypted: &EncryptedShare,
+        path: &Path,
+        trusted_dealer: Option<&VerifyingKey>,
+        decryptor: Option<&Decryptor>,
+    ) -> Result<ShareInfo> {
+        let format = format!("{:?}", InputFormat::Json);
+        match decryptor.map_or(Ok(None), |decryptor| decryptor.decrypt(encrypted, path)) {
+            Ok(Some(share_data)) => {
+                return Ok(Self::share_info(
+                    share_data,
+                    trusted_dealer,
+                    Some("decrypted"),
+                    format,
+                    path,
+                ));
+            }
+            Ok(None) => {}
+            Err(e) => log::warn!(
+                "Showing {} locked: {:#} (pass --share-passphrases to decrypt it)",
+                path.display(),
+                e
+            ),
+        }
+
+        Ok(ShareInfo {
+            id: encrypted.id.unwrap_or(0),
+            x_coordinate: encrypted.x,
+            y_length: encrypted.share_len,
+            threshold: encrypted.threshold,
+            total_shares: encrypted.total_shares,
+            set_id: encrypted.set_id.clone(),
+            fingerprint: None,
+            signature: None,
+            encryption: Some("locked"),
+            format,
+            file_path: Some(path.to_path_buf()),
+        })
+    }
 
-        let info = ShareInfo {
+    fn share_info(
+        share_data: ShareData,
+        trusted_dealer: Option<&VerifyingKey>,
+        encryption: Option<&'static str>,
+        format: String,
+        path: &Path,
+    ) -> ShareInfo {
+        ShareInfo {
             id: share_data.id.unwrap_or(0),
-            x_coordinate: share.x().value(),
-            y_length: share.y().len(),
+            x_coordinate: share_data.x,
+            y_length: share_data.y.len(),
             threshold: share_data.threshold,
             total_shares: share_data.total_shares,
-            format: format!("{:?}", format),
-            file_path: Some(path.clone()),
-        };
-
-        Ok((share, info))
+            signature: trusted_dealer.map(|key| signing::verify(key, &share_data)),
+            fingerprint: Some(share_data.fingerprint()),
+            set_id: share_data.set_id,
+            encryption,
+            format,
+            file_path: Some(path.to_path_buf()),
+        }
     }
 
-    fn analyze_share_set(
-        &self,
-        shares: Vec<fractus_shamir::Share>,
-        infos: Vec<ShareInfo>,
-    ) -> ShareSetInfo {
+    fn analyze_share_set(&self, infos: Vec<ShareInfo>) -> ShareSetInfo {
         let mut consistency_issues = Vec::new();
 
         // Check y-length consistency
-        let y_lengths: Vec<usize> = shares.iter().map(|s| s.y().len()).collect();
+        let y_lengths: Vec<usize> = infos.iter().map(|info| info.y_length).collect();
         let y_length = if y_lengths.is_empty() {
             None
         } else if y_lengths.iter().all(|&len| len == y_lengths[0]) {
@@ -165,8 +277,8 @@ impl InfoCommand {
 
         // Check for duplicate x-coordinates
         let mut x_coord_counts = HashMap::new();
-        for share in &shares {
-            *x_coord_counts.entry(share.x().value()).or_insert(0) += 1;
+        for info in &infos {
+            *x_coord_counts.entry(info.x_coordinate).or_insert(0) += 1;
         }
 
         let unique_x_coordinates = x_coord_counts.len();
@@ -179,14 +291,44 @@ impl InfoCommand {
             }
         }
 
+        // Flag shares the trusted dealer did not sign
+        for info in &infos {
+            if let Some(status @ (SignatureStatus::Invalid | SignatureStatus::Unsigned)) =
+                info.signature
+            {
+                consistency_issues.push(format!(
+                    "Share {}: signature is {}",
+                    info.x_coordinate, status
+                ));
+            } else if self.trusted_dealer.is_some() && info.signature.is_none() {
+                consistency_issues.push(format!(
+                    "Share {}: still encrypted, signature not checked",
+                    info.x_coordinate
+                ));
+            }
+        }
+
+        let mut set_ids: Vec<&str> = infos
+            .iter()
+            .filter_map(|info| info.set_id.as_deref())
+            .collect();
+        set_ids.sort_unstable();
+        set_ids.dedup();
+        if set_ids.len() > 1 {
+            consistency_issues.push(format!(
+                "Shares come from {} different share sets",
+                set_ids.len()
+            ));
+        }
+
         // Infer threshold from metadata or number of shares
         let inferred_threshold = infos
             .iter()
             .find_map(|info| info.threshold)
-            .or_else(|| Some(shares.len() as u8));
+            .or((!infos.is_empty()).then_some(infos.len() as u8));
 
         ShareSetInfo {
-            total_shares: shares.len(),
+            total_shares: infos.len(),
             unique_x_coordinates,
             y_length,
             inferred_threshold,
@@ -227,6 +369,32 @@ impl InfoCommand {
             println!("Inferred threshold: {}", threshold);
         }
 
+        let encrypted = info
+            .shares
+            .iter()
+            .filter(|share| share.encryption.is_some())
+            .count();
+        if encrypted > 0 {
+            let locked = info
+                .shares
+                .iter()
+                .filter(|share| share.encryption == Some("locked"))
+                .count();
+            println!("Encrypted shares: {} ({} not decrypted)", encrypted, locked);
+        }
+
+        if self.trusted_dealer.is_some() {
+            let valid = info
+                .shares
+                .iter()
+                .filter(|share| share.signature == Some(SignatureStatus::Valid))
+                .count();
+            println!(
+                "Signed by trusted dealer: {} of {}",
+                valid, info.total_shares
+            );
+        }
+
         if !info.consistency_issues.is_empty() {
             println!("\n⚠️  Consistency Issues:");
             for issue in &info.consistency_issues {
@@ -236,13 +404,19 @@ impl InfoCommand {
 
         if self.detailed && !info.shares.is_empty() {
             println!("\nIndividual Shares:");
-            println!("┌─────┬─────────────┬──────────┬───────────┬─────────────┬──────────┐");
-            println!("│ ID  │ X-Coord     │ Y-Length │ Threshold │ Total       │ Format   │");
-            println!("├─────┼─────────────┼──────────┼───────────┼─────────────┼──────────┤");
+            println!(
+                "┌─────┬─────────────┬──────────┬───────────┬─────────────┬──────────┬─────────────────────┐"
+            );
+            println!(
+                "│ ID  │ X-Coord     │ Y-Length │ Threshold │ Total       │ Format   │ Fingerprint         │"
+            );
+            println!(
+                "├─────┼─────────────┼──────────┼───────────┼─────────────┼──────────┼─────────────────────┤"
+            );
 
             for share in &info.shares {
                 println!(
-                    "│ {:<3} │ {:<11} │ {:<8} │ {:<9} │ {:<11} │ {:<8} │",
+                    "│ {:<3} │ {:<11} │ {:<8} │ {:<9} │ {:<11} │ {:<8} │ {:<19} │",
                     share.id,
                     share.x_coordinate,
                     share.y_length,
@@ -250,10 +424,13 @@ impl InfoCommand {
                     share
                         .total_shares
                         .map_or("?".to_string(), |t| t.to_string()),
-                    share.format
+                    share.format,
+                    share.fingerprint.as_deref().unwrap_or("?")
                 );
             }
-            println!("└─────┴─────────────┴──────────┴───────────┴─────────────┴──────────┘");
+            println!(
+                "└─────┴─────────────┴──────────┴───────────┴─────────────┴──────────┴─────────────────────┘"
+            );
         }
 
         // Recovery status
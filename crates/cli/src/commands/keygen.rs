@@ -0,0 +1,106 @@
+//! Keygen command implementation
+
+use anyhow::{Context, Result, bail};
+use clap::{ArgGroup, Args};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+use crate::config::Config;
+use crate::{recipients, signing};
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("kind").required(true).args(["signing", "age"])))]
+pub struct KeygenCommand {
+    /// Generate an Ed25519 dealer key for `split --sign-key`
+    #[arg(long)]
+    pub signing: bool,
+
+    /// Generate an age identity for receiving shares from `split --recipient`
+    #[arg(long)]
+    pub age: bool,
+
+    /// Secret key file (dealer.key or identity.txt by default); the public key
+    /// is written next to it with a .pub extension
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Overwrite existing key files
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl KeygenCommand {
+    pub fn execute(&self, _config: &Config) -> Result<()> {
+        let secret_path = self.output.clone().unwrap_or_else(|| {
+            PathBuf::from(if self.age {
+                "identity.txt"
+            } else {
+                "dealer.key"
+            })
+        });
+        let public_path = secret_path.with_extension("pub");
+        if public_path == secret_path {
+            bail!("The secret key file must not have a .pub extension");
+        }
+        if !self.force {
+            for path in [&secret_path, &public_path] {
+                if path.exists() {
+                    bail!(
+                        "{} already exists (pass --force to overwrite)",
+                        path.display()
+                    );
+                }
+            }
+        }
+
+        let (secret, public) = if self.age {
+            let identity = recipients::generate_identity();
+            (
+                recipients::identity_file(&identity),
+                identity.to_public().to_string(),
+            )
+        } else {
+            let key = signing::generate_key();
+            (
+                Zeroizing::new(format!("{}\n", hex::encode(key.to_bytes()))),
+                hex::encode(key.verifying_key().to_bytes()),
+            )
+        };
+
+        write_secret(&secret_path, &secret)?;
+        fs::write(&public_path, format!("{}\n", public))
+            .with_context(|| format!("Failed to write public key: {}", public_path.display()))?;
+
+        if self.age {
+            println!("✅ age identity written to {}", secret_path.display());
+            println!("Public key ({}): {}", public_path.display(), public);
+            println!(
+                "Give the public key to the dealer for `split --recipient NAME={}`",
+                public
+            );
+        } else {
+            println!("✅ Dealer signing key written to {}", secret_path.display());
+            println!("Public key ({}): {}", public_path.display(), public);
+            println!(
+                "Give the public key to custodians for `--trusted-dealer`; keep the secret key offline"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Writes a secret key readable only by its owner where the platform allows
+fn write_secret(path: &Path, secret: &str) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to create secret key: {}", path.display()))?;
+    file.write_all(secret.as_bytes())
+        .with_context(|| format!("Failed to write secret key: {}", path.display()))
+}
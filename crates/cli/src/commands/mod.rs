@@ -1,12 +1,18 @@
 //! Command implementations for the Fractus CLI
 
+mod dkg;
 mod info;
+mod keygen;
 mod recover;
 mod split;
+mod vss_verify;
 
+pub use dkg::DkgCommand;
 pub use info::InfoCommand;
+pub use keygen::KeygenCommand;
 pub use recover::RecoverCommand;
 pub use split::SplitCommand;
+pub use vss_verify::VssVerifyCommand;
 
 use crate::config::Config;
 use anyhow::Result;
@@ -22,6 +28,15 @@ pub enum Commands {
 
     /// Display information about shares
     Info(InfoCommand),
+
+    /// Generate a shared random secret without a trusted dealer
+    Dkg(DkgCommand),
+
+    /// Check VSS shares against the dealer's commitments
+    VssVerify(VssVerifyCommand),
+
+    /// Generate keys for signing or receiving shares
+    Keygen(KeygenCommand),
 }
 
 impl Commands {
@@ -30,6 +45,9 @@ impl Commands {
             Commands::Split(cmd) => cmd.execute(config),
             Commands::Recover(cmd) => cmd.execute(config),
             Commands::Info(cmd) => cmd.execute(config),
+            Commands::Dkg(cmd) => cmd.execute(config),
+            Commands::VssVerify(cmd) => cmd.execute(config),
+            Commands::Keygen(cmd) => cmd.execute(config),
         }
     }
 }
@@ -2,18 +2,26 @@
 
 use anyhow::{bail, Context, Result};
 use clap::Args;
-use fractus_shamir::{Shamir, Share};
+use fractus_shamir::mac::{self, AuthenticatedShare, Authentication};
+use fractus_shamir::{Shamir, Share, VerificationReport};
 use std::fs;
-use std::io::{self, BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
 
+use crate::armor;
 use crate::config::Config;
-use crate::formats::{InputFormat, ShareData};
+use crate::encryption::{Decryptor, EncryptedShare};
+use crate::formats::{CommitmentsData, InputFormat, ShareData, read_vss_shares};
+use crate::openpgp::{self, PgpSecretKey};
+use crate::qr;
+use crate::recipients;
+use crate::signing::{self, SignatureStatus};
+use crate::transcript;
 
 #[derive(Args)]
 pub struct RecoverCommand {
     /// Share files or directories to read from
-    #[arg(value_name = "SHARES", required = true)]
+    #[arg(value_name = "SHARES", required_unless_present_any = ["stdin", "interactive"])]
     pub inputs: Vec<PathBuf>,
 
     /// Input format (auto-detect if not specified)
@@ -24,107 +32,394 @@ pub struct RecoverCommand {
     #[arg(short, long, value_name = "FILE", default_value = "-")]
     pub output: String,
 
-    /// Expected threshold (for validation)
+    /// Threshold used to create the shares (inferred from metadata or the shares if omitted)
     #[arg(short = 'k', long, value_name = "THRESHOLD")]
     pub threshold: Option<u8>,
 
-    /// Read shares from stdin (one per line)
-    #[arg(long)]
+    /// Read shares from stdin (one per line, or any number of armored blocks
+    /// or transcribed shares)
+    #[arg(long, conflicts_with = "inputs")]
     pub stdin: bool,
 
-    /// Verify recovery by re-splitting and comparing
+    /// Prompt for shares to be typed or pasted in, checking transcribed
+    /// shares line by line
+    #[arg(long, conflicts_with_all = ["inputs", "stdin"])]
+    pub interactive: bool,
+
+    /// Check that every supplied share lies on the recovered polynomial
     #[arg(long)]
     pub verify: bool,
+
+    /// Recover VSS shares, checking each against the dealer's commitments
+    /// and ignoring those that do not match
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["stdin", "format", "threshold"])]
+    pub commitments: Option<PathBuf>,
+
+    /// Only use shares signed by this dealer public key (hex, or a .pub file
+    /// written by `fractus keygen --signing`)
+    #[arg(long, value_name = "PUBKEY", conflicts_with = "commitments")]
+    pub trusted_dealer: Option<String>,
+
+    /// Use shares without authentication data, unchecked, even when other
+    /// shares were split with --authenticate
+    #[arg(long)]
+    pub allow_unauthenticated: bool,
+
+    /// Try the passphrases in this file (one per line) on encrypted shares
+    /// instead of prompting
+    #[arg(long, value_name = "FILE")]
+    pub share_passphrases: Option<PathBuf>,
+
+    /// age identity file for shares encrypted with `split --recipient`
+    /// (repeatable)
+    #[arg(long, value_name = "FILE")]
+    pub identity: Vec<PathBuf>,
+
+    /// OpenPGP secret key file for shares encrypted with
+    /// `split --pgp-recipient` (repeatable)
+    #[arg(long, value_name = "FILE")]
+    pub pgp_key: Vec<PathBuf>,
 }
 
 impl RecoverCommand {
     pub fn execute(&self, _config: &Config) -> Result<()> {
+        if let Some(path) = &self.commitments {
+            return self.recover_verifiable(path);
+        }
+
         // Read shares
-        let shares = if self.stdin {
+        let share_data = if self.stdin {
             self.read_shares_from_stdin()?
+        } else if self.interactive {
+            self.read_shares_interactively()?
         } else {
-            self.read_shares_from_files()?
+            let decryptor = Decryptor::new(self.share_passphrases.as_deref())?;
+            let identities = recipients::read_identities(&self.identity)?;
+            let pgp_keys = openpgp::read_secret_keys(&self.pgp_key)?;
+            self.read_shares_from_files(&decryptor, &identities, &pgp_keys)?
         };
 
-        if shares.is_empty() {
+        if share_data.is_empty() {
             bail!("No shares provided");
         }
 
+        // Drop shares the trusted dealer did not sign
+        let share_data = match &self.trusted_dealer {
+            Some(key) => self.check_signatures(share_data, key)?,
+            None => share_data,
+        };
+
+        // Drop shares that were altered after dealing, if they can be checked
+        let share_data = self.authenticate(share_data)?;
+
+        // Determine threshold from the command line or share metadata
+        let threshold = self
+            .threshold
+            .or_else(|| share_data.iter().find_map(|data| data.threshold));
+
+        let shares: Vec<Share> = share_data.into_iter().map(ShareData::into_share).collect();
+
         // Validate shares
         self.validate_shares(&shares)?;
 
-        // Determine threshold from shares or use provided value
-        let threshold = if let Some(t) = self.threshold {
-            t
-        } else {
-            // Try to infer from share metadata or use minimum
-            shares.len() as u8
+        let secret = match threshold {
+            Some(threshold) => self.recover_with_threshold(&shares, threshold)?,
+            None => self.recover_inferring_threshold(&shares)?,
         };
 
-        // Create Shamir instance and recover
-        let shamir = Shamir::new(threshold).context("Failed to create Shamir instance")?;
+        // Output the recovered secret
+        self.output_secret(&secret)?;
 
-        let secret = shamir
-            .recover(&shares)
-            .context("Failed to recover secret from shares")?;
+        if self.output != "-" {
+            println!(
+                "✅ Secret successfully recovered from {} shares",
+                shares.len()
+            );
+        }
 
-        // Verify if requested
-        if self.verify {
-            self.verify_recovery(&secret, &shares, threshold)?;
+        Ok(())
+    }
+
+    fn recover_verifiable(&self, commitments_path: &Path) -> Result<()> {
+        let commitments = CommitmentsData::read(commitments_path)?;
+        let shares = read_vss_shares(&self.inputs)?;
+        if shares.is_empty() {
+            bail!("No VSS shares provided");
         }
 
-        // Output the recovered secret
+        let mut valid = Vec::new();
+        for (path, share) in shares {
+            match commitments.verify(&share) {
+                Ok(()) => valid.push(share),
+                Err(e) => log::warn!("Ignoring {}: {}", path.display(), e),
+            }
+        }
+
+        let secret = commitments
+            .recover(&valid)
+            .context("Failed to recover secret from the shares that match the commitments")?;
         self.output_secret(&secret)?;
 
         if self.output != "-" {
             println!(
-                "✅ Secret successfully recovered from {} shares",
-                shares.len()
+                "✅ Secret successfully recovered from {} verified shares",
+                valid.len()
             );
         }
 
         Ok(())
     }
 
-    fn read_shares_from_stdin(&self) -> Result<Vec<Share>> {
-        let mut shares = Vec::new();
-        let stdin = io::stdin();
-        let reader = BufReader::new(stdin.lock());
+    fn check_signatures(&self, share_data: Vec<ShareData>, key: &str) -> Result<Vec<ShareData>> {
+        let key = signing::parse_verifying_key(key)?;
+
+        let mut signed = Vec::new();
+        for data in share_data {
+            match signing::verify(&key, &data) {
+                SignatureStatus::Valid => signed.push(data),
+                SignatureStatus::Invalid => {
+                    log::warn!(
+                        "Ignoring share {}: signature does not match the trusted dealer",
+                        data.x
+                    )
+                }
+                SignatureStatus::Unsigned => {
+                    log::warn!("Ignoring share {}: not signed", data.x)
+                }
+            }
+        }
+
+        if signed.is_empty() {
+            bail!("No shares carry a valid signature from the trusted dealer");
+        }
+        if signed.iter().any(|data| data.set_id != signed[0].set_id) {
+            bail!("The signed shares come from different share sets");
+        }
+
+        Ok(signed)
+    }
+
+    fn authenticate(&self, share_data: Vec<ShareData>) -> Result<Vec<ShareData>> {
+        let (share_data, unauthenticated): (Vec<ShareData>, Vec<ShareData>) =
+            share_data.into_iter().partition(|data| data.auth.is_some());
+        if share_data.is_empty() {
+            return Ok(unauthenticated);
+        }
+
+        // Stripping the authentication data of one share must not switch off
+        // the check for all of them
+        for data in &unauthenticated {
+            if self.allow_unauthenticated {
+                log::warn!(
+                    "Share {} carries no authentication data; using it unchecked",
+                    data.x
+                );
+            } else {
+                log::warn!(
+                    "Ignoring share {}: it carries no authentication data while the others do \
+                     (pass --allow-unauthenticated to use it)",
+                    data.x
+                );
+            }
+        }
+        let unauthenticated = if self.allow_unauthenticated {
+            unauthenticated
+        } else {
+            Vec::new()
+        };
+
+        // Garbled authentication data is treated like a failed check, so it
+        // cannot abort the recovery either
+        let authenticated: Vec<AuthenticatedShare> = share_data
+            .iter()
+            .filter_map(|data| {
+                let auth = hex::decode(data.auth.as_deref().unwrap_or_default())
+                    .map_err(|e| e.to_string())
+                    .and_then(|bytes| {
+                        Authentication::from_bytes(&bytes).map_err(|e| e.to_string())
+                    });
+                match auth {
+                    Ok(auth) => Some(AuthenticatedShare {
+                        share: data.clone().into_share(),
+                        auth,
+                    }),
+                    Err(e) => {
+                        log::warn!(
+                            "Ignoring share {}: invalid authentication data: {}",
+                            data.x,
+                            e
+                        );
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        let report = mac::check(&authenticated);
+        for &(verifier, accused) in &report.accusations {
+            if report.rejected.contains(&accused) {
+                log::warn!(
+                    "Share {} failed authentication by share {}",
+                    accused,
+                    verifier
+                );
+            } else {
+                log::warn!(
+                    "Share {} rejected share {}, but was outvoted; its own keys may have been altered",
+                    verifier,
+                    accused
+                );
+            }
+        }
+        if report.is_authentic() {
+            log::info!("All {} shares passed authentication", report.accepted.len());
+        } else {
+            log::warn!(
+                "Ignoring shares altered after dealing: {:?}",
+                report.rejected
+            );
+        }
 
-        for line in reader.lines() {
-            let line = line.context("Failed to read line from stdin")?;
+        Ok(share_data
+            .into_iter()
+            .filter(|data| report.accepted.contains(&data.x))
+            .chain(unauthenticated)
+            .collect())
+    }
+
+    fn read_shares_from_stdin(&self) -> Result<Vec<ShareData>> {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read shares from stdin")?;
+
+        // Armored blocks span several lines and may be pasted with text around them
+        if matches!(self.format, Some(InputFormat::Armor))
+            || (self.format.is_none() && armor::is_armored(&input))
+        {
+            return armor::decode_all(&input);
+        }
+
+        // Transcribed shares take several lines each
+        if matches!(self.format, Some(InputFormat::Transcript))
+            || (self.format.is_none() && transcript::is_transcript(&input))
+        {
+            return transcript::decode_all(&input);
+        }
+
+        let mut shares = Vec::new();
+        for line in input.lines() {
             let line = line.trim();
 
             if line.is_empty() {
                 continue;
             }
 
-            let share = self.parse_share_from_string(line)?;
-            shares.push(share);
+            let line_shares = self.parse_shares_from_string(line)?;
+            shares.extend(line_shares);
+        }
+
+        Ok(shares)
+    }
+
+    fn read_shares_interactively(&self) -> Result<Vec<ShareData>> {
+        eprintln!("Type or paste one share at a time and finish with an empty line.");
+        eprintln!("Transcribed shares are entered line by line and checked as you go.");
+
+        let mut shares = Vec::new();
+        let mut decoder = transcript::Decoder::default();
+        let mut lines = io::stdin().lines();
+        loop {
+            match decoder.expected() {
+                Some((line, total)) => {
+                    eprint!("Share {}, line {}/{}: ", shares.len() + 1, line, total)
+                }
+                None => eprint!("Share {}: ", shares.len() + 1),
+            }
+            io::stderr().flush()?;
+
+            let Some(line) = lines.next() else {
+                eprintln!();
+                break;
+            };
+            let line = line.context("Failed to read share")?;
+            let line = line.trim();
+            if line.is_empty() {
+                if decoder.expected().is_none() {
+                    break;
+                }
+                continue;
+            }
+
+            // Lines of a transcribed share are checked one at a time; any
+            // other share is typed or pasted on a single line
+            let parsed = if decoder.expected().is_some()
+                || matches!(self.format, Some(InputFormat::Transcript))
+                || (self.format.is_none() && transcript::is_transcript(line))
+            {
+                decoder.push(line).map(|share| share.into_iter().collect())
+            } else {
+                self.parse_shares_from_string(line)
+            };
+
+            match parsed {
+                Ok(parsed) => {
+                    for share_data in parsed {
+                        eprintln!(
+                            "✅ Share {} accepted (fingerprint {})",
+                            shares.len() + 1,
+                            share_data.fingerprint()
+                        );
+                        shares.push(share_data);
+                    }
+                }
+                Err(e) => eprintln!("❌ {:#}. Please try again.", e),
+            }
         }
 
+        if let Some((line, total)) = decoder.expected() {
+            bail!(
+                "Share {} is incomplete: line {}/{} is missing",
+                shares.len() + 1,
+                line,
+                total
+            );
+        }
         Ok(shares)
     }
 
-    fn read_shares_from_files(&self) -> Result<Vec<Share>> {
+    fn read_shares_from_files(
+        &self,
+        decryptor: &Decryptor,
+        identities: &[Box<dyn age::Identity>],
+        pgp_keys: &[PgpSecretKey],
+    ) -> Result<Vec<ShareData>> {
         let mut shares = Vec::new();
 
         for input in &self.inputs {
             if input.is_dir() {
                 // Read all share files from directory
-                let dir_shares = self.read_shares_from_directory(input)?;
+                let dir_shares =
+                    self.read_shares_from_directory(input, decryptor, identities, pgp_keys)?;
                 shares.extend(dir_shares);
             } else {
                 // Read single file
-                let share = self.read_share_from_file(input)?;
-                shares.push(share);
+                let file_shares =
+                    self.read_shares_from_file(input, decryptor, identities, pgp_keys)?;
+                shares.extend(file_shares);
             }
         }
 
         Ok(shares)
     }
 
-    fn read_shares_from_directory(&self, dir: &PathBuf) -> Result<Vec<Share>> {
+    fn read_shares_from_directory(
+        &self,
+        dir: &PathBuf,
+        decryptor: &Decryptor,
+        identities: &[Box<dyn age::Identity>],
+        pgp_keys: &[PgpSecretKey],
+    ) -> Result<Vec<ShareData>> {
         let mut shares = Vec::new();
 
         let entries = fs::read_dir(dir)
@@ -136,8 +431,18 @@ impl RecoverCommand {
 
             if path.is_file() {
                 // Try to read as share file
-                if let Ok(share) = self.read_share_from_file(&path) {
-                    shares.push(share);
+                match self.read_shares_from_file(&path, decryptor, identities, pgp_keys) {
+                    Ok(file_shares) => shares.extend(file_shares),
+                    // Say which age and OpenPGP files could not be opened rather
+                    // than skipping them silently
+                    Err(e)
+                        if fs::read(&path).is_ok_and(|bytes| {
+                            recipients::is_age(&bytes) || openpgp::is_pgp(&bytes)
+                        }) =>
+                    {
+                        log::warn!("{:#}", e)
+                    }
+                    Err(_) => {}
                 }
             }
         }
@@ -145,7 +450,28 @@ impl RecoverCommand {
         Ok(shares)
     }
 
-    fn read_share_from_file(&self, path: &PathBuf) -> Result<Share> {
+    fn read_shares_from_file(
+        &self,
+        path: &PathBuf,
+        decryptor: &Decryptor,
+        identities: &[Box<dyn age::Identity>],
+        pgp_keys: &[PgpSecretKey],
+    ) -> Result<Vec<ShareData>> {
+        let bytes =
+            fs::read(path).with_context(|| format!("Failed to read file: {}", path.display()))?;
+        if recipients::is_age(&bytes) {
+            let plaintext = recipients::decrypt(identities, &bytes, path)?;
+            let share = serde_json::from_slice(&plaintext)
+                .with_context(|| format!("{} does not contain a share", path.display()))?;
+            return Ok(vec![share]);
+        }
+        if openpgp::is_pgp(&bytes) {
+            let plaintext = openpgp::decrypt(pgp_keys, &bytes, path)?;
+            let share = serde_json::from_slice(&plaintext)
+                .with_context(|| format!("{} does not contain a share", path.display()))?;
+            return Ok(vec![share]);
+        }
+
         // Detect format first (if not specified)
         let format = if let Some(f) = &self.format {
             *f
@@ -159,26 +485,31 @@ impl RecoverCommand {
                 let bytes = fs::read(path)
                     .with_context(|| format!("Failed to read binary file: {}", path.display()))?;
 
-                self.parse_share_from_bytes(&bytes)
+                Ok(vec![self.parse_share_from_bytes(&bytes)?])
             }
+            InputFormat::Qr => qr::decode_image(path),
             _ => {
                 // Read as string for text formats
                 let content = fs::read_to_string(path)
                     .with_context(|| format!("Failed to read file: {}", path.display()))?;
 
-                self.parse_share_from_string(&content)
+                if let Some(encrypted) = EncryptedShare::parse(&content) {
+                    let share = decryptor
+                        .decrypt(&encrypted, path)?
+                        .with_context(|| format!("Skipped encrypted share {}", path.display()))?;
+                    return Ok(vec![share]);
+                }
+
+                self.parse_shares_from_string(&content)
             }
         }
     }
 
-    fn parse_share_from_bytes(&self, bytes: &[u8]) -> Result<Share> {
-        let share_data =
-            ShareData::from_bytes(bytes).context("Failed to parse binary share data")?;
-
-        Ok(share_data.into_share())
+    fn parse_share_from_bytes(&self, bytes: &[u8]) -> Result<ShareData> {
+        ShareData::from_bytes(bytes).context("Failed to parse binary share data")
     }
 
-    fn parse_share_from_string(&self, content: &str) -> Result<Share> {
+    fn parse_shares_from_string(&self, content: &str) -> Result<Vec<ShareData>> {
         let content = content.trim();
 
         // Try to determine format from content if not specified
@@ -194,12 +525,18 @@ impl RecoverCommand {
             }
             InputFormat::Hex => ShareData::from_hex(content)?,
             InputFormat::Base64 => ShareData::from_base64(content)?,
+            InputFormat::Bech32m => ShareData::from_bech32m(content)?,
             InputFormat::Binary => {
                 bail!("Binary format requires byte input, not string");
             }
+            InputFormat::Armor => return armor::decode_all(content),
+            InputFormat::Transcript => return transcript::decode_all(content),
+            InputFormat::Qr => {
+                bail!("QR codes can only be read from image files");
+            }
         };
 
-        Ok(share_data.into_share())
+        Ok(vec![share_data])
     }
 
     fn validate_shares(&self, shares: &[Share]) -> Result<()> {
@@ -230,27 +567,83 @@ impl RecoverCommand {
         Ok(())
     }
 
-    fn verify_recovery(
-        &self,
-        secret: &[u8],
-        original_shares: &[Share],
-        threshold: u8,
-    ) -> Result<()> {
-        println!("Verifying recovery...");
+    fn recover_with_threshold(&self, shares: &[Share], threshold: u8) -> Result<Vec<u8>> {
+        let shamir = Shamir::new(threshold).context("Failed to create Shamir instance")?;
+
+        if self.verify {
+            let (secret, report) = shamir
+                .recover_verified(shares)
+                .context("Failed to recover secret from shares")?;
+            self.report_verification(&report, threshold)?;
+            Ok(secret)
+        } else {
+            shamir
+                .recover(shares)
+                .context("Failed to recover secret from shares")
+        }
+    }
+
+    fn recover_inferring_threshold(&self, shares: &[Share]) -> Result<Vec<u8>> {
+        let recovery = Shamir::recover_auto(shares)
+            .context("Failed to recover secret from shares (threshold unknown, pass -k to set it)")?;
+
+        for x in &recovery.inconsistent {
+            log::warn!(
+                "Share {} does not lie on the polynomial the other shares agree on; ignored",
+                x
+            );
+        }
+
+        if recovery.is_threshold_confirmed() {
+            log::info!(
+                "Inferred threshold {} from the shares; {} more share(s) could be lost",
+                recovery.threshold,
+                recovery.redundancy
+            );
+            if self.verify && recovery.inconsistent.is_empty() {
+                eprintln!(
+                    "✅ Verification successful: all {} shares lie on the recovered polynomial",
+                    shares.len()
+                );
+            } else if self.verify {
+                bail!(
+                    "Verification failed: shares with x-coordinates {:?} are inconsistent with the others",
+                    recovery.inconsistent
+                );
+            }
+        } else {
+            log::warn!(
+                "All {} shares were needed; the threshold could not be confirmed",
+                shares.len()
+            );
+            if self.verify {
+                eprintln!("⚠️  No surplus shares to verify: all shares were needed for recovery");
+            }
+        }
 
-        let shamir = Shamir::new(threshold)?;
-        let verification_shares: Vec<Share> =
-            shamir.split(secret)?.take(original_shares.len()).collect();
+        Ok(recovery.secret)
+    }
+
+    fn report_verification(&self, report: &VerificationReport, threshold: u8) -> Result<()> {
+        eprintln!("Verifying shares...");
 
-        // We can't directly compare shares since they'll have different random coefficients,
-        // but we can verify that we get the same secret back
-        let re_recovered = shamir.recover(&verification_shares[..threshold as usize])?;
+        let surplus = report.consistent.len() + report.inconsistent.len() - threshold as usize;
+        if surplus == 0 {
+            eprintln!("⚠️  No surplus shares to verify: all shares were needed for recovery");
+            return Ok(());
+        }
 
-        if re_recovered != secret {
-            bail!("Verification failed: re-splitting produced different secret");
+        if !report.is_consistent() {
+            bail!(
+                "Verification failed: shares with x-coordinates {:?} are inconsistent with the others",
+                report.inconsistent
+            );
         }
 
-        println!("✅ Verification successful");
+        eprintln!(
+            "✅ Verification successful: all {} shares lie on the recovered polynomial",
+            report.consistent.len()
+        );
         Ok(())
     }
 
@@ -2,15 +2,24 @@
 
 use anyhow::{bail, Context, Result};
 use clap::Args;
+use ed25519_dalek::SigningKey;
+use fractus_shamir::mac::Authentication;
+use fractus_shamir::passphrase::KdfParams;
+use fractus_shamir::vss::Vss;
 use fractus_shamir::{Shamir, Share};
 use rand_chacha::rand_core::SeedableRng;
 use std::fs;
-use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 use zeroize::Zeroizing;
 
 use crate::config::Config;
-use crate::formats::{OutputFormat, ShareData};
+use crate::encryption::{self, EncryptedShare};
+use crate::formats::{CommitmentsData, OutputFormat, ShareData, VssScheme, VssShareData};
+use crate::openpgp;
+use crate::paper;
+use crate::recipients::{self, NamedRecipient};
+use crate::signing;
 
 #[derive(Args)]
 pub struct SplitCommand {
@@ -50,10 +59,93 @@ pub struct SplitCommand {
     #[arg(long)]
     pub interactive: bool,
 
-    /// Custom seed for deterministic share generation (hex encoded)
+    /// Custom seed for deterministic share generation (hex encoded). The
+    /// same seed only gives the same shares with the same fractus version;
+    /// use --passphrase for shares that can be re-created later.
     #[arg(long, value_name = "HEX")]
     pub seed: Option<String>,
 
+    /// Derive the shares from a passphrase (prompted) and --salt, so the same
+    /// share set can be re-created later
+    #[arg(long, requires = "salt", conflicts_with = "seed")]
+    pub passphrase: bool,
+
+    /// Read the passphrase for a derived share set from a file
+    #[arg(
+        long,
+        value_name = "FILE",
+        requires = "salt",
+        conflicts_with_all = ["seed", "passphrase"]
+    )]
+    pub passphrase_file: Option<PathBuf>,
+
+    /// Salt for a passphrase-derived share set (at least 8 characters)
+    #[arg(long, value_name = "TEXT")]
+    pub salt: Option<String>,
+
+    /// Deal verifiable shares with this commitment scheme, and write the
+    /// dealer's commitments to commitments.json next to them
+    #[arg(
+        long,
+        value_enum,
+        value_name = "SCHEME",
+        conflicts_with_all = ["passphrase", "passphrase_file", "stdout"]
+    )]
+    pub vss: Option<VssScheme>,
+
+    /// Attach MAC tags and keys to every share, so that holders can check
+    /// each other's shares and a tampered share can be pinned on its holder
+    #[arg(long, conflicts_with_all = ["passphrase", "passphrase_file", "vss"])]
+    pub authenticate: bool,
+
+    /// Sign every share with this dealer key (see `fractus keygen --signing`),
+    /// so that recovery can reject shares the dealer did not write
+    #[arg(long, value_name = "FILE", conflicts_with = "vss")]
+    pub sign_key: Option<PathBuf>,
+
+    /// Encrypt every share under its own passphrase (prompted per share)
+    #[arg(long, conflicts_with = "vss")]
+    pub encrypt_shares: bool,
+
+    /// Read the share passphrases from a file, one per line in share order
+    /// and exactly one per share
+    #[arg(long, value_name = "FILE", requires = "encrypt_shares")]
+    pub share_passphrases: Option<PathBuf>,
+
+    /// Encrypt each share to a custodian's age public key, one per share
+    /// (repeatable, e.g. --recipient alice=age1...)
+    #[arg(
+        long,
+        value_name = "NAME=AGE1...",
+        conflicts_with_all = ["vss", "encrypt_shares", "stdout"]
+    )]
+    pub recipient: Vec<NamedRecipient>,
+
+    /// Encrypt each share to a custodian's OpenPGP public key file, one per
+    /// share (repeatable); shares are written as armored `.asc` messages
+    #[arg(
+        long,
+        value_name = "KEYFILE",
+        conflicts_with_all = ["vss", "encrypt_shares", "recipient", "stdout"]
+    )]
+    pub pgp_recipient: Vec<PathBuf>,
+
+    /// Write a printable HTML sheet for each share to this directory instead
+    /// of share files
+    #[arg(
+        long,
+        value_name = "DIR",
+        conflicts_with_all = [
+            "output_dir", "format", "stdout", "vss", "authenticate", "sign_key",
+            "encrypt_shares", "recipient", "pgp_recipient"
+        ]
+    )]
+    pub paper: Option<PathBuf>,
+
+    /// Custodian named on a paper sheet, one per share (repeatable)
+    #[arg(long, value_name = "NAME", requires = "paper")]
+    pub custodian: Vec<String>,
+
     /// Include metadata in output
     #[arg(long)]
     pub include_metadata: bool,
@@ -64,38 +156,80 @@ impl SplitCommand {
         // Validate arguments
         self.validate()?;
 
+        let signing_key = self
+            .sign_key
+            .as_deref()
+            .map(signing::read_signing_key)
+            .transpose()?;
+        let pgp_recipients = self
+            .pgp_recipient
+            .iter()
+            .map(|path| openpgp::read_recipient(path))
+            .collect::<Result<Vec<_>>>()?;
+
         // Read the secret
         let secret = self.read_secret()?;
 
+        if let Some(scheme) = self.vss {
+            return self.split_verifiable(&secret, scheme);
+        }
+
         // Create Shamir instance
         let shamir = Shamir::new(self.threshold).context("Failed to create Shamir instance")?;
 
         // Generate shares
-        let shares = if let Some(seed_hex) = &self.seed {
-            let seed_bytes = hex::decode(seed_hex).context("Invalid hex seed")?;
-            if seed_bytes.len() != 32 {
-                bail!("Seed must be exactly 32 bytes (64 hex characters)");
+        let (shares, auth) = if self.authenticate {
+            let authenticated = match self.seeded_rng()? {
+                Some(mut rng) => shamir.split_authenticated(&secret, self.shares, &mut rng),
+                None => shamir.split_authenticated(&secret, self.shares, &mut rand::thread_rng()),
             }
-            let mut seed = [0u8; 32];
-            seed.copy_from_slice(&seed_bytes);
-            let mut rng = rand_chacha::ChaCha8Rng::from_seed(seed);
-
-            shamir
+            .context("Failed to split the secret")?;
+            authenticated
+                .into_iter()
+                .map(|authenticated| (authenticated.share, Some(authenticated.auth)))
+                .unzip()
+        } else if let Some(passphrase) = self.read_passphrase()? {
+            let salt = self.salt.as_deref().unwrap_or_default();
+            let shares = shamir
+                .split_from_passphrase(
+                    &secret,
+                    passphrase.as_bytes(),
+                    salt.as_bytes(),
+                    &KdfParams::default(),
+                )
+                .context("Failed to derive shares from the passphrase")?
+                .take(self.shares as usize)
+                .collect::<Vec<Share>>();
+            (shares, Vec::new())
+        } else if let Some(mut rng) = self.seeded_rng()? {
+            let shares = shamir
                 .split_with_rng(&secret, &mut rng)?
                 .take(self.shares as usize)
-                .collect::<Vec<Share>>()
+                .collect::<Vec<Share>>();
+            (shares, Vec::new())
         } else {
-            shamir
+            let shares = shamir
                 .split(&secret)?
                 .take(self.shares as usize)
-                .collect::<Vec<Share>>()
+                .collect::<Vec<Share>>();
+            (shares, Vec::new())
         };
+        let share_data = self.share_data(shares, auth, signing_key.as_ref())?;
 
         // Output shares
-        if self.stdout {
-            self.output_to_stdout(&shares)?;
+        if let Some(dir) = &self.paper {
+            self.output_paper(&share_data, dir)?;
+        } else if !self.recipient.is_empty() {
+            self.output_to_recipients(&share_data)?;
+        } else if !pgp_recipients.is_empty() {
+            self.output_to_pgp_recipients(&share_data, &pgp_recipients)?;
+        } else if self.encrypt_shares {
+            let encrypted = self.encrypt(&share_data)?;
+            self.output_encrypted(&encrypted)?;
+        } else if self.stdout {
+            self.output_to_stdout(&share_data)?;
         } else {
-            self.output_to_files(&shares)?;
+            self.output_to_files(&share_data)?;
         }
 
         if !self.stdout {
@@ -103,7 +237,9 @@ impl SplitCommand {
                 "✅ Successfully generated {} shares with threshold {}",
                 self.shares, self.threshold
             );
-            if let Some(dir) = &self.output_dir {
+            if let Some(dir) = &self.paper {
+                println!("Paper sheets saved to: {}", dir.display());
+            } else if let Some(dir) = &self.output_dir {
                 println!("Shares saved to: {}", dir.display());
             }
         }
@@ -130,6 +266,112 @@ impl SplitCommand {
             bail!("Only one input method can be specified");
         }
 
+        // These options write their own JSON documents
+        let json_only = [
+            ("--vss", self.vss.is_some()),
+            ("--authenticate", self.authenticate),
+            ("--sign-key", self.sign_key.is_some()),
+            ("--encrypt-shares", self.encrypt_shares),
+            ("--recipient", !self.recipient.is_empty()),
+            ("--pgp-recipient", !self.pgp_recipient.is_empty()),
+        ];
+        if !matches!(self.format, OutputFormat::Json)
+            && let Some((option, _)) = json_only.iter().find(|(_, used)| *used)
+        {
+            bail!("{} shares can only be written as JSON", option);
+        }
+
+        if !self.recipient.is_empty() && self.recipient.len() != self.shares as usize {
+            bail!(
+                "Got {} recipients for {} shares; give one --recipient per share",
+                self.recipient.len(),
+                self.shares
+            );
+        }
+
+        if !self.custodian.is_empty() && self.custodian.len() != self.shares as usize {
+            bail!(
+                "Got {} custodians for {} shares; give one --custodian per share",
+                self.custodian.len(),
+                self.shares
+            );
+        }
+
+        if !self.pgp_recipient.is_empty() && self.pgp_recipient.len() != self.shares as usize {
+            bail!(
+                "Got {} OpenPGP keys for {} shares; give one --pgp-recipient per share",
+                self.pgp_recipient.len(),
+                self.shares
+            );
+        }
+
+        if let Some(salt) = &self.salt {
+            if !self.passphrase && self.passphrase_file.is_none() {
+                bail!("--salt requires --passphrase or --passphrase-file");
+            }
+            if salt.len() < KdfParams::MIN_SALT_LEN {
+                bail!(
+                    "Salt must be at least {} bytes long",
+                    KdfParams::MIN_SALT_LEN
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn seeded_rng(&self) -> Result<Option<rand_chacha::ChaCha8Rng>> {
+        let Some(seed_hex) = &self.seed else {
+            return Ok(None);
+        };
+        let seed_bytes = hex::decode(seed_hex).context("Invalid hex seed")?;
+        if seed_bytes.len() != 32 {
+            bail!("Seed must be exactly 32 bytes (64 hex characters)");
+        }
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&seed_bytes);
+        Ok(Some(rand_chacha::ChaCha8Rng::from_seed(seed)))
+    }
+
+    fn split_verifiable(&self, secret: &[u8], scheme: VssScheme) -> Result<()> {
+        let vss = Vss::new(self.threshold, scheme.into()).context("Failed to create VSS dealer")?;
+        let (commitments, shares) = match self.seeded_rng()? {
+            Some(mut rng) => vss.split_with_rng(secret, self.shares, &mut rng),
+            None => vss.split_with_rng(secret, self.shares, &mut rand::thread_rng()),
+        }
+        .context("Failed to split the secret")?;
+
+        let output_dir = self.create_output_dir()?;
+
+        let commitments_path = output_dir.join("commitments.json");
+        fs::write(
+            &commitments_path,
+            serde_json::to_string_pretty(&CommitmentsData::new(&commitments))?,
+        )?;
+
+        for share in &shares {
+            let share_data = VssShareData::new(
+                share,
+                commitments.scheme(),
+                self.shares,
+                self.threshold,
+                self.include_metadata,
+            );
+            let filename = format!("{}-{:03}.json", self.base_name, share.x);
+            fs::write(
+                output_dir.join(filename),
+                serde_json::to_string_pretty(&share_data)?,
+            )?;
+        }
+
+        println!(
+            "✅ Successfully generated {} verifiable shares with threshold {}",
+            self.shares, self.threshold
+        );
+        println!(
+            "Publish {} to every custodian so they can run `fractus vss-verify`",
+            commitments_path.display()
+        );
         Ok(())
     }
 
@@ -163,40 +405,185 @@ impl SplitCommand {
         Ok(Zeroizing::new(secret))
     }
 
-    fn output_to_stdout(&self, shares: &[Share]) -> Result<()> {
-        for (i, share) in shares.iter().enumerate() {
-            let share_data = ShareData::new(
-                share.clone(),
-                i + 1,
-                self.shares,
-                self.threshold,
-                self.include_metadata,
+    fn read_passphrase(&self) -> Result<Option<Zeroizing<String>>> {
+        let passphrase = if let Some(path) = &self.passphrase_file {
+            let mut contents = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read passphrase file: {}", path.display()))?;
+            // Editors usually add a trailing newline that is not part of the passphrase
+            let len = contents.trim_end_matches(['\r', '\n']).len();
+            contents.truncate(len);
+            contents
+        } else if self.passphrase {
+            let passphrase = Zeroizing::new(
+                rpassword::prompt_password("Enter passphrase: ")
+                    .context("Failed to read passphrase")?,
+            );
+            let confirmation = Zeroizing::new(
+                rpassword::prompt_password("Confirm passphrase: ")
+                    .context("Failed to read passphrase")?,
             );
+            if passphrase != confirmation {
+                bail!("Passphrases do not match");
+            }
+            passphrase.to_string()
+        } else {
+            return Ok(None);
+        };
 
-            match self.format {
-                OutputFormat::Json => {
-                    println!("{}", serde_json::to_string_pretty(&share_data)?);
-                }
-                OutputFormat::Hex => {
-                    println!("{}", share_data.to_hex());
-                }
-                OutputFormat::Base64 => {
-                    println!("{}", share_data.to_base64());
+        if passphrase.is_empty() {
+            bail!("Passphrase cannot be empty");
+        }
+
+        Ok(Some(Zeroizing::new(passphrase)))
+    }
+
+    fn share_data(
+        &self,
+        shares: Vec<Share>,
+        auth: Vec<Option<Authentication>>,
+        signing_key: Option<&SigningKey>,
+    ) -> Result<Vec<ShareData>> {
+        // Armored shares and paper sheets always show their metadata
+        let include_metadata = self.include_metadata
+            || self.paper.is_some()
+            || matches!(self.format, OutputFormat::Armor);
+        // Signatures bind the set id and threshold, so signed shares always carry them
+        let set_id = (include_metadata || signing_key.is_some()).then(signing::generate_set_id);
+        let auth = auth.into_iter().chain(std::iter::repeat(None));
+        shares
+            .into_iter()
+            .zip(auth)
+            .enumerate()
+            .map(|(i, (share, auth))| {
+                let mut share_data =
+                    ShareData::new(share, i + 1, self.shares, self.threshold, include_metadata);
+                share_data.set_id = set_id.clone();
+                share_data.auth = auth.map(|auth| hex::encode(auth.to_bytes()));
+                if let Some(key) = signing_key {
+                    share_data.threshold = Some(self.threshold);
+                    signing::sign(key, &mut share_data)?;
                 }
-                OutputFormat::Binary => {
-                    let bytes = share_data.to_bytes();
-                    io::stdout().write_all(&bytes)?;
+                Ok(share_data)
+            })
+            .collect()
+    }
+
+    fn encrypt(&self, shares: &[ShareData]) -> Result<Vec<EncryptedShare>> {
+        let passphrases = match &self.share_passphrases {
+            Some(path) => {
+                let passphrases = encryption::read_passphrases(path)?;
+                if passphrases.len() != shares.len() {
+                    bail!(
+                        "{} has {} passphrases but there are {} shares; give one per share",
+                        path.display(),
+                        passphrases.len(),
+                        shares.len()
+                    );
                 }
+                passphrases
+            }
+            None => (1..=shares.len())
+                .map(|i| self.prompt_share_passphrase(i))
+                .collect::<Result<_>>()?,
+        };
+
+        shares
+            .iter()
+            .zip(&passphrases)
+            .map(|(share_data, passphrase)| EncryptedShare::seal(share_data, passphrase))
+            .collect()
+    }
+
+    fn prompt_share_passphrase(&self, i: usize) -> Result<Zeroizing<String>> {
+        let prompt = format!("Passphrase for share {} of {}: ", i, self.shares);
+        let passphrase = Zeroizing::new(
+            rpassword::prompt_password(prompt).context("Failed to read passphrase")?,
+        );
+        if passphrase.is_empty() {
+            bail!("Passphrase cannot be empty");
+        }
+        let confirmation = Zeroizing::new(
+            rpassword::prompt_password("Confirm passphrase: ")
+                .context("Failed to read passphrase")?,
+        );
+        if passphrase != confirmation {
+            bail!("Passphrases do not match");
+        }
+        Ok(passphrase)
+    }
+
+    fn output_encrypted(&self, shares: &[EncryptedShare]) -> Result<()> {
+        if self.stdout {
+            for share in shares {
+                println!("{}", serde_json::to_string_pretty(share)?);
             }
+            return Ok(());
+        }
+
+        let output_dir = self.create_output_dir()?;
+        for (i, share) in shares.iter().enumerate() {
+            let filename = format!("{}-{:03}.json", self.base_name, i + 1);
+            fs::write(
+                output_dir.join(filename),
+                serde_json::to_string_pretty(share)?,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn output_to_recipients(&self, shares: &[ShareData]) -> Result<()> {
+        let output_dir = self.create_output_dir()?;
+        for (i, (share_data, recipient)) in shares.iter().zip(&self.recipient).enumerate() {
+            let plaintext = Zeroizing::new(serde_json::to_vec_pretty(share_data)?);
+            let armored = recipients::encrypt(recipient, &plaintext)?;
+            let filename = format!("{}-{:03}-{}.age", self.base_name, i + 1, recipient.name);
+            fs::write(output_dir.join(&filename), armored)?;
+            log::info!(
+                "Share {} encrypted to {} in {}",
+                i + 1,
+                recipient.name,
+                filename
+            );
         }
         Ok(())
     }
 
-    fn output_to_files(&self, shares: &[Share]) -> Result<()> {
+    fn output_to_pgp_recipients(
+        &self,
+        shares: &[ShareData],
+        pgp_recipients: &[openpgp::PgpRecipient],
+    ) -> Result<()> {
+        let output_dir = self.create_output_dir()?;
+        for (i, (share_data, recipient)) in shares.iter().zip(pgp_recipients).enumerate() {
+            let plaintext = Zeroizing::new(serde_json::to_vec_pretty(share_data)?);
+            let armored = openpgp::encrypt(recipient, &plaintext)?;
+            let filename = format!("{}-{:03}-{}.asc", self.base_name, i + 1, recipient.name);
+            fs::write(output_dir.join(&filename), armored)?;
+            log::info!(
+                "Share {} encrypted to {} in {}",
+                i + 1,
+                recipient.name,
+                filename
+            );
+        }
+        Ok(())
+    }
+
+    fn output_paper(&self, shares: &[ShareData], dir: &Path) -> Result<()> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+        for (i, share_data) in shares.iter().enumerate() {
+            let custodian = self.custodian.get(i).map(String::as_str);
+            let filename = format!("{}-{:03}.html", self.base_name, i + 1);
+            fs::write(dir.join(filename), paper::sheet(share_data, custodian)?)?;
+        }
+        Ok(())
+    }
+
+    fn create_output_dir(&self) -> Result<PathBuf> {
         let output_dir = self
             .output_dir
-            .as_ref()
-            .map(|p| p.clone())
+            .clone()
             .unwrap_or_else(|| PathBuf::from("."));
 
         // Create output directory if it doesn't exist
@@ -205,15 +592,20 @@ impl SplitCommand {
                 .with_context(|| format!("Failed to create directory: {}", output_dir.display()))?;
         }
 
-        for (i, share) in shares.iter().enumerate() {
-            let share_data = ShareData::new(
-                share.clone(),
-                i + 1,
-                self.shares,
-                self.threshold,
-                self.include_metadata,
-            );
+        Ok(output_dir)
+    }
 
+    fn output_to_stdout(&self, shares: &[ShareData]) -> Result<()> {
+        for share_data in shares {
+            share_data.print(self.format)?;
+        }
+        Ok(())
+    }
+
+    fn output_to_files(&self, shares: &[ShareData]) -> Result<()> {
+        let output_dir = self.create_output_dir()?;
+
+        for (i, share_data) in shares.iter().enumerate() {
             let filename = format!(
                 "{}-{:03}.{}",
                 self.base_name,
@@ -221,21 +613,11 @@ impl SplitCommand {
                 self.format.extension()
             );
             let filepath = output_dir.join(filename);
+            share_data.write(self.format, &filepath)?;
 
-            match self.format {
-                OutputFormat::Json => {
-                    let content = serde_json::to_string_pretty(&share_data)?;
-                    fs::write(&filepath, content)?;
-                }
-                OutputFormat::Hex => {
-                    fs::write(&filepath, share_data.to_hex())?;
-                }
-                OutputFormat::Base64 => {
-                    fs::write(&filepath, share_data.to_base64())?;
-                }
-                OutputFormat::Binary => {
-                    fs::write(&filepath, share_data.to_bytes())?;
-                }
+            // PNG for printing and scanning, SVG for scaling without blur
+            if matches!(self.format, OutputFormat::Qr) {
+                share_data.write(self.format, &filepath.with_extension("svg"))?;
             }
         }
 
@@ -0,0 +1,59 @@
+//! VSS verify command implementation
+
+use anyhow::{Result, bail};
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::config::Config;
+use crate::formats::{CommitmentsData, read_vss_shares};
+
+#[derive(Args)]
+pub struct VssVerifyCommand {
+    /// Commitments file published by the dealer
+    #[arg(long, value_name = "FILE")]
+    pub commitments: PathBuf,
+
+    /// VSS share files or directories to check
+    #[arg(value_name = "SHARES", required = true)]
+    pub inputs: Vec<PathBuf>,
+}
+
+impl VssVerifyCommand {
+    pub fn execute(&self, _config: &Config) -> Result<()> {
+        let commitments = CommitmentsData::read(&self.commitments)?;
+        let shares = read_vss_shares(&self.inputs)?;
+        if shares.is_empty() {
+            bail!("No VSS shares found");
+        }
+
+        let mut failed = 0;
+        for (path, share) in &shares {
+            match commitments.verify(share) {
+                Ok(()) => println!(
+                    "✅ Share {} ({}) matches the commitments",
+                    share.x,
+                    path.display()
+                ),
+                Err(e) => {
+                    failed += 1;
+                    println!("❌ Share {} ({}): {}", share.x, path.display(), e);
+                }
+            }
+        }
+
+        if failed > 0 {
+            bail!(
+                "{} of {} shares do not match the dealer's commitments",
+                failed,
+                shares.len()
+            );
+        }
+
+        println!(
+            "All {} shares are consistent; any {} of them recover the secret",
+            shares.len(),
+            commitments.threshold()
+        );
+        Ok(())
+    }
+}
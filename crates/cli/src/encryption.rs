@@ -0,0 +1,298 @@
+//! Passphrase-encrypted share files
+//!
+//! `split --encrypt-shares` seals each share under its custodian's passphrase,
+//! so a copied share file is useless without the passphrase as well. An
+//! encrypted share is a JSON document whose non-secret metadata stays readable:
+//!
+//! ```json
+//! {
+//!   "format": "fractus-encrypted-share",
+//!   "version": 1,
+//!   "id": 1,
+//!   "x": 1,
+//!   "share_len": 36,
+//!   "set_id": "...", "threshold": 3, "total_shares": 5, "created_at": "...",
+//!   "kdf": { "algorithm": "argon2id", "memory_kib": 65536, "iterations": 3,
+//!            "parallelism": 1, "salt": "<16 bytes, hex>" },
+//!   "cipher": "xchacha20poly1305",
+//!   "nonce": "<24 bytes, hex>",
+//!   "ciphertext": "<base64>"
+//! }
+//! ```
+//!
+//! The key is Argon2id (version 0x13, 32-byte output) over the passphrase and
+//! salt with the recorded costs. The plaintext is the share's regular JSON
+//! form, encrypted with XChaCha20-Poly1305 under associated data
+//! `"fractus-encrypted-share-v1"`. `share_len` is the length of the share's
+//! y-values, and `id`, `set_id`, `threshold`, `total_shares` and `created_at` are
+//! copied from the share when present. Decryption rejects a header that
+//! disagrees with the decrypted share.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow, bail};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use fractus_shamir::passphrase::KdfParams;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::formats::ShareData;
+
+const FORMAT: &str = "fractus-encrypted-share";
+const VERSION: u8 = 1;
+const AAD: &[u8] = b"fractus-encrypted-share-v1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// A share sealed under a custodian's passphrase
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EncryptedShare {
+    pub format: String,
+    pub version: u8,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u8>,
+
+    /// X-coordinate of the share
+    pub x: u8,
+
+    /// Number of y-values in the share
+    pub share_len: usize,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_shares: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    pub kdf: Kdf,
+    pub cipher: String,
+
+    /// Hex-encoded nonce
+    pub nonce: String,
+
+    /// Base64-encoded ciphertext and tag
+    pub ciphertext: String,
+}
+
+/// Key derivation parameters of an encrypted share
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Kdf {
+    pub algorithm: String,
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+
+    /// Hex-encoded salt
+    pub salt: String,
+}
+
+impl EncryptedShare {
+    /// Encrypts a share under a passphrase with fresh salt and nonce
+    pub fn seal(share_data: &ShareData, passphrase: &str) -> Result<Self> {
+        let params = KdfParams::default();
+        let salt: [u8; SALT_LEN] = rand::random();
+        let nonce: [u8; NONCE_LEN] = rand::random();
+
+        let key = derive_key(passphrase, &salt, &params)?;
+        let plaintext = Zeroizing::new(serde_json::to_vec(share_data)?);
+        let ciphertext = XChaCha20Poly1305::new(key.as_slice().into())
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: AAD,
+                },
+            )
+            .map_err(|_| anyhow!("Failed to encrypt share {}", share_data.x))?;
+
+        Ok(Self {
+            format: FORMAT.to_string(),
+            version: VERSION,
+            id: share_data.id,
+            x: share_data.x,
+            share_len: share_data.y.len(),
+            set_id: share_data.set_id.clone(),
+            threshold: share_data.threshold,
+            total_shares: share_data.total_shares,
+            created_at: share_data.created_at.clone(),
+            kdf: Kdf {
+                algorithm: "argon2id".to_string(),
+                memory_kib: params.memory_kib,
+                iterations: params.iterations,
+                parallelism: params.parallelism,
+                salt: hex::encode(salt),
+            },
+            cipher: "xchacha20poly1305".to_string(),
+            nonce: hex::encode(nonce),
+            ciphertext: BASE64.encode(ciphertext),
+        })
+    }
+
+    /// Parses an encrypted share, returning `None` if the content is not one
+    pub fn parse(content: &str) -> Option<Self> {
+        serde_json::from_str::<Self>(content)
+            .ok()
+            .filter(|share| share.format == FORMAT)
+    }
+
+    /// Decrypts the share, failing if the passphrase is wrong or the file was altered
+    pub fn open(&self, passphrase: &str) -> Result<ShareData> {
+        let share_data = self.decrypt(passphrase)?;
+        self.check_header(&share_data)?;
+        Ok(share_data)
+    }
+
+    /// Decrypts the share without comparing it to the header
+    fn decrypt(&self, passphrase: &str) -> Result<ShareData> {
+        if self.version != VERSION {
+            bail!("Unsupported encrypted share version {}", self.version);
+        }
+        if self.kdf.algorithm != "argon2id" || self.cipher != "xchacha20poly1305" {
+            bail!(
+                "Unsupported encryption: {} with {}",
+                self.kdf.algorithm,
+                self.cipher
+            );
+        }
+
+        let salt = hex::decode(&self.kdf.salt).context("Invalid salt encoding")?;
+        let nonce = hex::decode(&self.nonce).context("Invalid nonce encoding")?;
+        if nonce.len() != NONCE_LEN {
+            bail!("Invalid nonce length");
+        }
+        let ciphertext = BASE64
+            .decode(self.ciphertext.trim())
+            .context("Invalid ciphertext encoding")?;
+
+        let params = KdfParams {
+            memory_kib: self.kdf.memory_kib,
+            iterations: self.kdf.iterations,
+            parallelism: self.kdf.parallelism,
+        };
+        let key = derive_key(passphrase, &salt, &params)?;
+        let plaintext = Zeroizing::new(
+            XChaCha20Poly1305::new(key.as_slice().into())
+                .decrypt(
+                    XNonce::from_slice(&nonce),
+                    Payload {
+                        msg: &ciphertext,
+                        aad: AAD,
+                    },
+                )
+                .map_err(|_| anyhow!("Wrong passphrase or corrupted share {}", self.x))?,
+        );
+        serde_json::from_slice(&plaintext).context("Decrypted share is not valid JSON")
+    }
+
+    /// Checks that the metadata stored in the clear matches the decrypted share
+    fn check_header(&self, share_data: &ShareData) -> Result<()> {
+        let header_matches = share_data.x == self.x
+            && share_data.y.len() == self.share_len
+            && (self.set_id.is_none() || share_data.set_id == self.set_id)
+            && (self.threshold.is_none() || share_data.threshold == self.threshold)
+            && (self.total_shares.is_none() || share_data.total_shares == self.total_shares);
+        if !header_matches {
+            bail!(
+                "The metadata of encrypted share {} does not match its contents",
+                self.x
+            );
+        }
+        Ok(())
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], params: &KdfParams) -> Result<Zeroizing<[u8; 32]>> {
+    let params = Params::new(
+        params.memory_kib,
+        params.iterations,
+        params.parallelism,
+        Some(32),
+    )
+    .map_err(|e| anyhow!("Invalid key derivation parameters: {}", e))?;
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut_slice())
+        .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+/// Reads passphrases from a file, one per line
+pub fn read_passphrases(path: &Path) -> Result<Vec<Zeroizing<String>>> {
+    let content = Zeroizing::new(
+        fs::read_to_string(path)
+            .with_context(|| format!("Failed to read passphrase file: {}", path.display()))?,
+    );
+    Ok(content
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .filter(|line| !line.is_empty())
+        .map(|line| Zeroizing::new(line.to_string()))
+        .collect())
+}
+
+/// Decrypts encrypted shares, trying passphrases from a file or prompting
+/// for each share
+pub struct Decryptor {
+    passphrases: Option<Vec<Zeroizing<String>>>,
+}
+
+impl Decryptor {
+    pub fn new(passphrase_file: Option<&Path>) -> Result<Self> {
+        Ok(Self {
+            passphrases: passphrase_file.map(read_passphrases).transpose()?,
+        })
+    }
+
+    /// Decrypts a share, or returns `None` if no passphrase from the file
+    /// opens it or the user skips it at the prompt
+    pub fn decrypt(&self, share: &EncryptedShare, source: &Path) -> Result<Option<ShareData>> {
+        if let Some(passphrases) = &self.passphrases {
+            let Some(share_data) = passphrases
+                .iter()
+                .find_map(|passphrase| share.decrypt(passphrase).ok())
+            else {
+                log::warn!(
+                    "Skipping {}: no passphrase in the passphrase file decrypts it",
+                    source.display()
+                );
+                return Ok(None);
+            };
+            if let Err(e) = share.check_header(&share_data) {
+                log::warn!("Skipping {}: {}", source.display(), e);
+                return Ok(None);
+            }
+            return Ok(Some(share_data));
+        }
+
+        let prompt = format!(
+            "Passphrase for share {} ({}, empty to skip): ",
+            share.x,
+            source.display()
+        );
+        loop {
+            let passphrase = Zeroizing::new(
+                rpassword::prompt_password(&prompt).context("Failed to read passphrase")?,
+            );
+            if passphrase.is_empty() {
+                return Ok(None);
+            }
+            match share.open(&passphrase) {
+                Ok(share_data) => return Ok(Some(share_data)),
+                Err(e) => eprintln!("{}; try again", e),
+            }
+        }
+    }
+}
@@ -1,11 +1,19 @@
 //! Input/Output format handling
 
-use std::{fs, path::PathBuf};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Context, Result};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use clap::ValueEnum;
 use fractus_shamir::Share;
+use fractus_shamir::vss::{Commitments, Scheme, VssShare};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{armor, bech32m, qr, transcript};
 
 #[derive(ValueEnum, Clone, Copy, Debug)]
 pub enum OutputFormat {
@@ -13,6 +21,10 @@ pub enum OutputFormat {
     Hex,
     Base64,
     Binary,
+    Armor,
+    Bech32m,
+    Qr,
+    Transcript,
 }
 
 #[derive(ValueEnum, Clone, Copy, Debug)]
@@ -21,6 +33,10 @@ pub enum InputFormat {
     Hex,
     Base64,
     Binary,
+    Armor,
+    Bech32m,
+    Qr,
+    Transcript,
 }
 
 impl OutputFormat {
@@ -30,6 +46,10 @@ impl OutputFormat {
             Self::Hex => "hex",
             Self::Base64 => "b64",
             Self::Binary => "bin",
+            Self::Armor => "txt",
+            Self::Bech32m => "bech32",
+            Self::Qr => "png",
+            Self::Transcript => "b32",
         }
     }
 }
@@ -42,6 +62,9 @@ impl InputFormat {
             Some("hex") => Ok(InputFormat::Hex),
             Some("b64") | Some("base64") => Ok(InputFormat::Base64),
             Some("bin") | Some("binary") => Ok(InputFormat::Binary),
+            Some("bech32") => Ok(InputFormat::Bech32m),
+            Some("png") | Some("jpg") | Some("jpeg") => Ok(InputFormat::Qr),
+            Some("b32") => Ok(InputFormat::Transcript),
             _ => {
                 // If we can't detect from extension, try reading a small sample
                 Self::detect_from_file_content(path)
@@ -69,6 +92,10 @@ impl InputFormat {
 
         let sample = &buffer[..bytes_read];
 
+        if qr::is_image(sample) {
+            return Ok(InputFormat::Qr);
+        }
+
         // Check if it's valid UTF-8 first
         if let Ok(text) = std::str::from_utf8(sample) {
             Self::detect_from_content(text)
@@ -86,13 +113,28 @@ impl InputFormat {
             bail!("Empty content");
         }
 
+        // Armored blocks may be surrounded by other text, as in an email
+        if armor::is_armored(content) {
+            return Ok(InputFormat::Armor);
+        }
+
+        // Numbered lines of groups are neither hex nor base64
+        if transcript::is_transcript(content) {
+            return Ok(InputFormat::Transcript);
+        }
+
+        // Checked before hex and base64, which can both look like a Bech32m string
+        if bech32m::is_bech32m(content) {
+            return Ok(InputFormat::Bech32m);
+        }
+
         // JSON detection
         if content.starts_with('{') && content.ends_with('}') {
             return Ok(InputFormat::Json);
         }
 
         // Hex detection (only hex characters)
-        if content.len() % 2 == 0 && content.chars().all(|c| c.is_ascii_hexdigit()) {
+        if content.len().is_multiple_of(2) && content.chars().all(|c| c.is_ascii_hexdigit()) {
             return Ok(InputFormat::Hex);
         }
 
@@ -109,7 +151,7 @@ impl InputFormat {
         let content = content.replace(['\n', '\r', ' '], ""); // Remove whitespace
 
         // Check if length is valid for base64
-        if content.len() % 4 != 0 {
+        if !content.len().is_multiple_of(4) {
             return false;
         }
 
@@ -131,6 +173,10 @@ pub struct ShareData {
     /// Y-coordinates (the actual share data)
     pub y: Vec<u8>,
 
+    /// Random id shared by every share of one split
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_id: Option<String>,
+
     /// Metadata (only included if requested)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub threshold: Option<u8>,
@@ -143,6 +189,14 @@ pub struct ShareData {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Hex-encoded MAC tags and keys (see `split --authenticate`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<String>,
+
+    /// Hex-encoded Ed25519 dealer signature (see `split --sign-key`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
 }
 
 impl ShareData {
@@ -161,6 +215,7 @@ impl ShareData {
             },
             x: share.x().value(),
             y: share.y().iter().map(|gf| gf.value()).collect(),
+            set_id: None,
             threshold: if include_metadata {
                 Some(threshold)
             } else {
@@ -177,6 +232,8 @@ impl ShareData {
                 None
             },
             description: None,
+            auth: None,
+            signature: None,
         }
     }
 
@@ -203,10 +260,13 @@ impl ShareData {
             id: None,
             x: share.x().value(),
             y: share.y().iter().map(|gf| gf.value()).collect(),
+            set_id: None,
             threshold: None,
             total_shares: None,
             created_at: None,
             description: None,
+            auth: None,
+            signature: None,
         })
     }
 
@@ -220,11 +280,266 @@ impl ShareData {
     }
 
     pub fn to_base64(&self) -> String {
-        base64::encode(self.to_bytes())
+        BASE64.encode(self.to_bytes())
     }
 
     pub fn from_base64(b64_str: &str) -> Result<Self> {
-        let bytes = base64::decode(b64_str.trim()).context("Invalid base64 encoding")?;
+        let bytes = BASE64.decode(b64_str.trim()).context("Invalid base64 encoding")?;
         Self::from_bytes(&bytes)
     }
+
+    pub fn to_armor(&self) -> String {
+        armor::encode(self)
+    }
+
+    pub fn to_bech32m(&self) -> Result<String> {
+        bech32m::encode(&self.to_bytes())
+    }
+
+    pub fn from_bech32m(s: &str) -> Result<Self> {
+        Self::from_bytes(&bech32m::decode(s)?)
+    }
+
+    pub fn to_transcript(&self) -> String {
+        transcript::encode(self)
+    }
+
+    /// Writes the share to a file in the given format; QR codes are written
+    /// as PNG, or as SVG if the path ends in .svg
+    pub fn write(&self, format: OutputFormat, path: &Path) -> Result<()> {
+        match format {
+            OutputFormat::Qr => qr::write_image(&qr::encode(self)?, path),
+            _ => Ok(fs::write(path, self.encode(format)?)?),
+        }
+    }
+
+    /// Prints the share to stdout in the given format, QR codes as text art
+    /// headed by the share's x-coordinate
+    pub fn print(&self, format: OutputFormat) -> Result<()> {
+        if matches!(format, OutputFormat::Qr) {
+            println!("Share {}", self.x);
+        }
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(&self.encode(format)?)?;
+        // Binary shares are raw bytes and armored blocks end in a newline
+        if !matches!(format, OutputFormat::Binary | OutputFormat::Armor) {
+            writeln!(stdout)?;
+        }
+        Ok(())
+    }
+
+    fn encode(&self, format: OutputFormat) -> Result<Vec<u8>> {
+        Ok(match format {
+            OutputFormat::Json => serde_json::to_vec_pretty(self)?,
+            OutputFormat::Hex => self.to_hex().into_bytes(),
+            OutputFormat::Base64 => self.to_base64().into_bytes(),
+            OutputFormat::Binary => self.to_bytes(),
+            OutputFormat::Armor => self.to_armor().into_bytes(),
+            OutputFormat::Bech32m => self.to_bech32m()?.into_bytes(),
+            OutputFormat::Qr => qr::to_terminal(&qr::encode(self)?).into_bytes(),
+            OutputFormat::Transcript => self.to_transcript().into_bytes(),
+        })
+    }
+
+    /// Short SHA-256 fingerprint of the share bytes, printed on paper sheets
+    /// so that a transcribed share can be checked
+    pub fn fingerprint(&self) -> String {
+        let digest = Sha256::digest(self.to_bytes());
+        hex::encode_upper(&digest[..8])
+            .as_bytes()
+            .chunks(4)
+            .map(|group| std::str::from_utf8(group).expect("hex is ASCII"))
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+/// Commitment scheme for verifiable secret sharing
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VssScheme {
+    /// Commitments reveal a hash-like image of the secret
+    Feldman,
+    /// Hiding commitments, at the cost of larger shares
+    Pedersen,
+}
+
+impl From<VssScheme> for Scheme {
+    fn from(scheme: VssScheme) -> Self {
+        match scheme {
+            VssScheme::Feldman => Scheme::Feldman,
+            VssScheme::Pedersen => Scheme::Pedersen,
+        }
+    }
+}
+
+impl From<Scheme> for VssScheme {
+    fn from(scheme: Scheme) -> Self {
+        match scheme {
+            Scheme::Feldman => VssScheme::Feldman,
+            Scheme::Pedersen => VssScheme::Pedersen,
+        }
+    }
+}
+
+/// A share of a verifiably shared secret, written by `split --vss`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VssShareData {
+    /// Share ID (1-based index)
+    pub id: Option<u8>,
+
+    /// X-coordinate of the share
+    pub x: u8,
+
+    /// Commitment scheme the share was dealt with
+    pub scheme: VssScheme,
+
+    /// Hex-encoded share
+    pub share: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_shares: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+impl VssShareData {
+    pub fn new(
+        share: &VssShare,
+        scheme: Scheme,
+        total_shares: u8,
+        threshold: u8,
+        include_metadata: bool,
+    ) -> Self {
+        Self {
+            id: include_metadata.then_some(share.x),
+            x: share.x,
+            scheme: scheme.into(),
+            share: hex::encode(share.to_bytes()),
+            threshold: include_metadata.then_some(threshold),
+            total_shares: include_metadata.then_some(total_shares),
+            created_at: include_metadata.then(|| chrono::Utc::now().to_rfc3339()),
+        }
+    }
+
+    pub fn to_share(&self) -> Result<VssShare> {
+        let bytes = hex::decode(self.share.trim()).context("Invalid hex encoding")?;
+        let share = VssShare::from_bytes(&bytes, self.scheme.into())
+            .map_err(|e| anyhow::anyhow!("Failed to parse VSS share: {}", e))?;
+        if share.x != self.x {
+            bail!(
+                "Share data has x-coordinate {} but is labelled {}",
+                share.x,
+                self.x
+            );
+        }
+        Ok(share)
+    }
+}
+
+/// The dealer's published commitments, written by `split --vss`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommitmentsData {
+    pub scheme: VssScheme,
+    pub threshold: u8,
+    pub secret_len: usize,
+
+    /// Hex-encoded commitments; the fields above are for display only
+    pub commitments: String,
+}
+
+impl CommitmentsData {
+    pub fn new(commitments: &Commitments) -> Self {
+        Self {
+            scheme: commitments.scheme().into(),
+            threshold: commitments.threshold(),
+            secret_len: commitments.secret_len(),
+            commitments: hex::encode(commitments.to_bytes()),
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Commitments> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read commitments: {}", path.display()))?;
+        let data: CommitmentsData = serde_json::from_str(&content)
+            .with_context(|| format!("{} is not a commitments file", path.display()))?;
+        let bytes = hex::decode(data.commitments.trim()).context("Invalid hex encoding")?;
+        Commitments::from_bytes(&bytes)
+            .map_err(|e| anyhow::anyhow!("Invalid commitments in {}: {}", path.display(), e))
+    }
+}
+
+/// Reads VSS shares from files and directories, skipping files in directories
+/// that are not VSS shares (such as the commitments file).
+pub fn read_vss_shares(inputs: &[PathBuf]) -> Result<Vec<(PathBuf, VssShare)>> {
+    let read = |path: &Path| -> Result<VssShare> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        serde_json::from_str::<VssShareData>(&content)
+            .with_context(|| format!("{} is not a VSS share", path.display()))?
+            .to_share()
+    };
+
+    let mut shares = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            let mut paths = Vec::new();
+            for entry in fs::read_dir(input)
+                .with_context(|| format!("Failed to read directory: {}", input.display()))?
+            {
+                let path = entry?.path();
+                if path.is_file() {
+                    paths.push(path);
+                }
+            }
+            paths.sort();
+            for path in paths {
+                if let Ok(share) = read(&path) {
+                    shares.push((path, share));
+                }
+            }
+        } else {
+            shares.push((input.clone(), read(input)?));
+        }
+    }
+    Ok(shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_bech32m_before_hex_and_base64() {
+        // A Bech32m string whose length is a multiple of four is also valid base64
+        let encoded = (1..8)
+            .map(|len| bech32m::encode(&vec![0xab; len]).unwrap())
+            .find(|encoded| encoded.len().is_multiple_of(4))
+            .unwrap();
+        assert!(InputFormat::is_likely_base64(&encoded));
+
+        for content in [encoded.clone(), encoded.to_ascii_uppercase()] {
+            assert!(matches!(
+                InputFormat::detect_from_content(&content).unwrap(),
+                InputFormat::Bech32m
+            ));
+        }
+    }
+
+    #[test]
+    fn test_detect_hex_and_base64() {
+        let share_data = ShareData::from_bytes(&[1, 0xab, 0xcd, 0xef]).unwrap();
+        assert!(matches!(
+            InputFormat::detect_from_content(&share_data.to_hex()).unwrap(),
+            InputFormat::Hex
+        ));
+        assert!(matches!(
+            InputFormat::detect_from_content(&share_data.to_base64()).unwrap(),
+            InputFormat::Base64
+        ));
+    }
 }
@@ -3,11 +3,18 @@
 //! This tool provides a comprehensive interface for splitting secrets into shares
 //! and recovering them using Shamir's Secret Sharing scheme.
 
+mod armor;
+mod bech32m;
 mod commands;
 mod config;
-mod error;
+mod encryption;
 mod formats;
-mod utils;
+mod openpgp;
+mod paper;
+mod qr;
+mod recipients;
+mod signing;
+mod transcript;
 
 use anyhow::Result;
 use clap::Parser;
@@ -0,0 +1,145 @@
+//! Encrypting shares to custodians' OpenPGP keys
+//!
+//! `split --pgp-recipient key.asc` writes each share's JSON as an
+//! ASCII-armored OpenPGP message (SEIPD v1, AES-256) encrypted to the
+//! certificate's encryption subkey, so custodians who only use GnuPG can
+//! open it with `gpg --decrypt`. Everything is done with rPGP; no `gpg`
+//! binary is needed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
+use pgp::composed::{
+    ArmorOptions, Deserializable, Message, MessageBuilder, SignedPublicKey, SignedSecretKey,
+};
+use pgp::crypto::sym::SymmetricKeyAlgorithm;
+use pgp::types::{KeyDetails, Password};
+use zeroize::Zeroizing;
+
+const ARMOR_BEGIN: &[u8] = b"-----BEGIN PGP MESSAGE-----";
+
+/// A custodian's OpenPGP certificate, named after the file it was read from
+pub struct PgpRecipient {
+    pub name: String,
+    pub cert: SignedPublicKey,
+}
+
+/// An OpenPGP secret key with the passphrase that unlocks it
+pub struct PgpSecretKey {
+    key: SignedSecretKey,
+    password: Password,
+}
+
+/// Reads a custodian's public key, armored or binary
+pub fn read_recipient(path: &Path) -> Result<PgpRecipient> {
+    let bytes = fs::read(path)
+        .with_context(|| format!("Failed to read OpenPGP key: {}", path.display()))?;
+    let (cert, _) = SignedPublicKey::from_reader_single(bytes.as_slice())
+        .map_err(|e| anyhow!("{} is not an OpenPGP public key: {}", path.display(), e))?;
+    cert.verify_bindings()
+        .map_err(|e| anyhow!("Invalid OpenPGP key {}: {}", path.display(), e))?;
+
+    let name = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("recipient")
+        .to_string();
+    Ok(PgpRecipient { name, cert })
+}
+
+/// Encrypts a share to a recipient as an ASCII-armored OpenPGP message
+pub fn encrypt(recipient: &PgpRecipient, plaintext: &[u8]) -> Result<String> {
+    let mut rng = rand::thread_rng();
+    let mut builder = MessageBuilder::from_bytes("", plaintext.to_vec())
+        .seipd_v1(&mut rng, SymmetricKeyAlgorithm::AES256);
+
+    let cert = &recipient.cert;
+    let subkey = cert.public_subkeys.iter().find(|subkey| {
+        subkey.key.algorithm().can_encrypt()
+            && subkey.signatures.iter().any(|signature| {
+                let flags = signature.key_flags();
+                flags.encrypt_comms() || flags.encrypt_storage()
+            })
+    });
+    match subkey {
+        Some(subkey) => builder.encrypt_to_key(&mut rng, &subkey.key),
+        None if cert.primary_key.algorithm().can_encrypt() => {
+            builder.encrypt_to_key(&mut rng, &cert.primary_key)
+        }
+        None => bail!(
+            "OpenPGP key {} ({}) has no encryption key",
+            recipient.name,
+            cert.fingerprint()
+        ),
+    }
+    .map_err(|e| anyhow!("Failed to encrypt to {}: {}", recipient.name, e))?;
+
+    builder
+        .to_armored_string(&mut rng, ArmorOptions::default())
+        .map_err(|e| anyhow!("Failed to encrypt to {}: {}", recipient.name, e))
+}
+
+/// Whether the content is an ASCII-armored OpenPGP message
+pub fn is_pgp(content: &[u8]) -> bool {
+    content.trim_ascii_start().starts_with(ARMOR_BEGIN)
+}
+
+/// Reads OpenPGP secret keys, prompting for the passphrase of protected ones
+pub fn read_secret_keys(paths: &[PathBuf]) -> Result<Vec<PgpSecretKey>> {
+    paths
+        .iter()
+        .map(|path| {
+            let bytes = fs::read(path)
+                .with_context(|| format!("Failed to read OpenPGP key: {}", path.display()))?;
+            let (key, _) = SignedSecretKey::from_reader_single(bytes.as_slice())
+                .map_err(|e| anyhow!("{} is not an OpenPGP secret key: {}", path.display(), e))?;
+
+            let protected = key.primary_key.secret_params().is_encrypted()
+                || key
+                    .secret_subkeys
+                    .iter()
+                    .any(|subkey| subkey.key.secret_params().is_encrypted());
+            let password = if protected {
+                let prompt = format!("Passphrase for OpenPGP key {}: ", key.fingerprint());
+                let passphrase = Zeroizing::new(
+                    rpassword::prompt_password(prompt).context("Failed to read passphrase")?,
+                );
+                Password::from(passphrase.as_str())
+            } else {
+                Password::empty()
+            };
+
+            Ok(PgpSecretKey { key, password })
+        })
+        .collect()
+}
+
+/// Decrypts an armored OpenPGP message with whichever key it was encrypted to
+pub fn decrypt(keys: &[PgpSecretKey], content: &[u8], source: &Path) -> Result<Zeroizing<Vec<u8>>> {
+    if keys.is_empty() {
+        bail!(
+            "{} is an OpenPGP message; pass --pgp-key to decrypt it",
+            source.display()
+        );
+    }
+
+    let (message, _) = Message::from_armor(content)
+        .map_err(|e| anyhow!("{} is not a valid OpenPGP message: {}", source.display(), e))?;
+    let mut message = message
+        .decrypt_with_keys(
+            keys.iter().map(|key| &key.password).collect(),
+            keys.iter().map(|key| &key.key).collect(),
+        )
+        .map_err(|e| anyhow!("Failed to decrypt {}: {}", source.display(), e))?;
+    if message.is_compressed() {
+        message = message
+            .decompress()
+            .map_err(|e| anyhow!("Failed to decompress {}: {}", source.display(), e))?;
+    }
+
+    let plaintext = message
+        .as_data_vec()
+        .with_context(|| format!("Failed to decrypt {}", source.display()))?;
+    Ok(Zeroizing::new(plaintext))
+}
@@ -0,0 +1,128 @@
+//! Printable paper backups
+//!
+//! `split --paper <dir>` writes one self-contained HTML sheet per share, to be
+//! printed or saved as PDF from any browser. A sheet shows the share as a QR
+//! code and as the numbered, checksummed lines of `--format transcript`,
+//! together with the set id, threshold, custodian, creation date, the share's
+//! fingerprint and instructions for recovery. Each line carries its own
+//! checksum, so a mistake made while typing a sheet back in is pinned to its
+//! line. Nothing is loaded from the network, so sheets can be generated and
+//! printed offline.
+
+use anyhow::Result;
+use qrcode::render::svg;
+
+use crate::formats::ShareData;
+use crate::qr;
+use crate::transcript;
+
+const STYLE: &str = "
+@page { size: A4; margin: 18mm; }
+body { font-family: Helvetica, Arial, sans-serif; color: #000; max-width: 180mm; margin: 0 auto; }
+h1 { font-size: 20pt; margin-bottom: 2mm; }
+h2 { font-size: 13pt; margin: 7mm 0 2mm; border-bottom: 1px solid #000; }
+table.meta td { padding: 1mm 4mm 1mm 0; vertical-align: top; }
+table.meta td:first-child { font-weight: bold; }
+.mono { font-family: 'Courier New', Courier, monospace; }
+.blank { display: inline-block; min-width: 70mm; border-bottom: 1px solid #000; }
+.qr { float: right; width: 55mm; margin-left: 6mm; }
+.qr svg { width: 100%; height: auto; }
+table.groups { font-family: 'Courier New', Courier, monospace; font-size: 14pt; border-collapse: collapse; }
+table.groups td { padding: 1mm 2.5mm; }
+table.groups td.row { color: #555; font-size: 10pt; text-align: right; }
+table.groups td.check { font-weight: bold; padding-left: 6mm; }
+ol li { margin-bottom: 1.5mm; }
+.warning { border: 2px solid #000; padding: 3mm; margin-top: 7mm; }
+";
+
+/// Renders the paper sheet of a share
+pub fn sheet(share_data: &ShareData, custodian: Option<&str>) -> Result<String> {
+    let index = share_data.id.unwrap_or(share_data.x);
+    let total = share_data
+        .total_shares
+        .map_or_else(|| "?".to_string(), |total| total.to_string());
+    let threshold = share_data
+        .threshold
+        .map_or_else(|| "?".to_string(), |threshold| threshold.to_string());
+    let set_id = share_data.set_id.as_deref().unwrap_or("-");
+    let created = share_data.created_at.as_deref().map_or_else(
+        || "-".to_string(),
+        |created| {
+            chrono::DateTime::parse_from_rfc3339(created).map_or_else(
+                |_| created.to_string(),
+                |date| date.format("%Y-%m-%d %H:%M UTC").to_string(),
+            )
+        },
+    );
+    let custodian = custodian.map_or_else(|| "<span class=\"blank\"></span>".to_string(), escape);
+    let fingerprint = share_data.fingerprint();
+
+    let code = qr::encode(share_data)?;
+    let svg = code.render::<svg::Color>().quiet_zone(true).build();
+    // Inline the image without its XML declaration
+    let svg = &svg[svg.find("<svg").unwrap_or(0)..];
+
+    let rows: String = transcript::lines(share_data)
+        .iter()
+        .map(|line| {
+            let cells: String = line
+                .groups
+                .iter()
+                .map(|group| format!("<td>{}</td>", group))
+                .collect();
+            format!(
+                "<tr><td class=\"row\">{}</td>{}<td class=\"check\">{}</td></tr>\n",
+                line.label, cells, line.checksum
+            )
+        })
+        .collect();
+
+    Ok(format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Fractus share {index} of {total}</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<h1>Secret share {index} of {total}</h1>
+<p>This sheet holds one share of a secret split with Fractus. Any {threshold} shares of this set recover the secret; fewer reveal nothing about it. Keep the sheet private.</p>
+
+<div class="qr">{svg}</div>
+<table class="meta">
+<tr><td>Custodian</td><td>{custodian}</td></tr>
+<tr><td>Share</td><td>{index} of {total}</td></tr>
+<tr><td>Threshold</td><td>{threshold}</td></tr>
+<tr><td>Set id</td><td class="mono">{set_id}</td></tr>
+<tr><td>Created</td><td>{created}</td></tr>
+<tr><td>Fingerprint</td><td class="mono">{fingerprint}</td></tr>
+</table>
+
+<h2>Share</h2>
+<table class="groups">
+{rows}</table>
+<p>Each line starts with its number and the number of lines, and ends in a two-character checksum of the line. A mistyped character is reported with the number of its line. The letters I, L, O and U are never used: read I and L as 1 and O as 0.</p>
+
+<h2>Recovering the secret</h2>
+<ol>
+<li>Collect at least {threshold} shares with set id <span class="mono">{set_id}</span>.</li>
+<li>Install the <span class="mono">fractus</span> command-line tool on an offline computer.</li>
+<li>For each share, either photograph or scan its QR code and save the image as PNG or JPEG, or type the lines above into a text file, one per line, including the line numbers and checksums. Case does not matter. <span class="mono">fractus recover --interactive</span> instead checks each line as it is typed.</li>
+<li>Check each file with <span class="mono">fractus info --detailed &lt;file&gt;</span>: the fingerprint must read <span class="mono">{fingerprint}</span> for this share.</li>
+<li>Run <span class="mono">fractus recover &lt;share files&gt; -o secret</span> to write the secret to the file <span class="mono">secret</span>.</li>
+</ol>
+
+<p class="warning">Anyone holding {threshold} shares of this set can recover the secret. Do not copy, photograph or share this sheet except to recover the secret.</p>
+</body>
+</html>
+"#
+    ))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,101 @@
+//! QR codes of shares
+//!
+//! `--format qr` encodes each share's Bech32m string in upper case, which QR
+//! codes store in their compact alphanumeric mode. Codes are printed to the
+//! terminal as blocks of black on white, or written as PNG and SVG images for
+//! printing. Recovery reads PNG and JPEG images, photos included, and decodes
+//! every QR code in them.
+//!
+//! The error correction level is chosen per share: the highest of H, Q, M and
+//! L that keeps the code within version 20 (97×97 modules), so ordinary shares
+//! survive a quarter of the code being damaged while long ones stay printable.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow, bail};
+use qrcode::render::{svg, unicode};
+use qrcode::{EcLevel, QrCode, Version};
+
+use crate::formats::ShareData;
+
+const MAX_VERSION: i16 = 20;
+const MODULE_SIZE: u32 = 8;
+
+const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+const JPEG_MAGIC: &[u8] = b"\xff\xd8\xff";
+
+/// Encodes a share as a QR code with an automatically chosen error
+/// correction level
+pub fn encode(share_data: &ShareData) -> Result<QrCode> {
+    let payload = share_data.to_bech32m()?.to_ascii_uppercase();
+
+    let mut smallest = None;
+    for level in [EcLevel::H, EcLevel::Q, EcLevel::M, EcLevel::L] {
+        let Ok(code) = QrCode::with_error_correction_level(&payload, level) else {
+            continue;
+        };
+        if matches!(code.version(), Version::Normal(version) if version <= MAX_VERSION) {
+            return Ok(code);
+        }
+        smallest = Some(code);
+    }
+    smallest.ok_or_else(|| anyhow!("Share {} is too long for a QR code", share_data.x))
+}
+
+/// Renders a QR code for the terminal, forcing black on white so that it
+/// scans on dark terminal themes as well
+pub fn to_terminal(code: &QrCode) -> String {
+    code.render::<unicode::Dense1x2>()
+        .quiet_zone(true)
+        .build()
+        .lines()
+        .map(|line| format!("\x1b[30;47m{}\x1b[0m\n", line))
+        .collect()
+}
+
+/// Writes a QR code as an SVG image if the path ends in `.svg`, and as a PNG
+/// image otherwise
+pub fn write_image(code: &QrCode, path: &Path) -> Result<()> {
+    if path.extension().is_some_and(|ext| ext == "svg") {
+        let image = code
+            .render::<svg::Color>()
+            .module_dimensions(MODULE_SIZE, MODULE_SIZE)
+            .build();
+        std::fs::write(path, image)?;
+    } else {
+        code.render::<image::Luma<u8>>()
+            .module_dimensions(MODULE_SIZE, MODULE_SIZE)
+            .build()
+            .save_with_format(path, image::ImageFormat::Png)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Whether the content is a PNG or JPEG image
+pub fn is_image(content: &[u8]) -> bool {
+    content.starts_with(PNG_MAGIC) || content.starts_with(JPEG_MAGIC)
+}
+
+/// Decodes every share QR code in an image
+pub fn decode_image(path: &Path) -> Result<Vec<ShareData>> {
+    let image = image::open(path)
+        .with_context(|| format!("Failed to read image: {}", path.display()))?
+        .to_luma8();
+    let mut prepared = rqrr::PreparedImage::prepare(image);
+
+    let grids = prepared.detect_grids();
+    if grids.is_empty() {
+        bail!("No QR code found in {}", path.display());
+    }
+    grids
+        .iter()
+        .map(|grid| {
+            let (_, content) = grid
+                .decode()
+                .map_err(|e| anyhow!("Unreadable QR code in {}: {}", path.display(), e))?;
+            ShareData::from_bech32m(&content)
+                .with_context(|| format!("QR code in {} is not a share", path.display()))
+        })
+        .collect()
+}
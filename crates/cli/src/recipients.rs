@@ -0,0 +1,140 @@
+//! Encrypting shares to custodians' age public keys
+//!
+//! `split --recipient name=age1...` writes each share's JSON as an
+//! ASCII-armored [age](https://age-encryption.org) file that only the named
+//! custodian's identity can open, so shares can travel over email or chat.
+//! Files are standard age and can also be decrypted with the `age` tool.
+
+use std::fs;
+use std::io::{BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use age::armor::{ArmoredReader, ArmoredWriter, Format};
+use age::secrecy::ExposeSecret;
+use anyhow::{Context, Result, anyhow, bail};
+use zeroize::Zeroizing;
+
+const BINARY_MAGIC: &[u8] = b"age-encryption.org/";
+const ARMOR_BEGIN: &[u8] = b"-----BEGIN AGE ENCRYPTED FILE-----";
+
+/// A custodian's name and age public key, given as `name=age1...`
+#[derive(Clone, Debug)]
+pub struct NamedRecipient {
+    pub name: String,
+    pub recipient: age::x25519::Recipient,
+}
+
+impl FromStr for NamedRecipient {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (name, key) = s
+            .split_once('=')
+            .ok_or_else(|| "expected NAME=AGE_PUBLIC_KEY".to_string())?;
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(format!(
+                "invalid recipient name '{}': use letters, digits, '-', '_' or '.'",
+                name
+            ));
+        }
+        let recipient = key
+            .trim()
+            .parse()
+            .map_err(|e| format!("invalid age public key for '{}': {}", name, e))?;
+        Ok(Self {
+            name: name.to_string(),
+            recipient,
+        })
+    }
+}
+
+/// Generates a fresh age identity
+pub fn generate_identity() -> age::x25519::Identity {
+    age::x25519::Identity::generate()
+}
+
+/// Serializes an identity in the format of `age-keygen`
+pub fn identity_file(identity: &age::x25519::Identity) -> Zeroizing<String> {
+    Zeroizing::new(format!(
+        "# created: {}\n# public key: {}\n{}\n",
+        chrono::Utc::now().to_rfc3339(),
+        identity.to_public(),
+        identity.to_string().expose_secret()
+    ))
+}
+
+/// Encrypts a share to a recipient as an ASCII-armored age file
+pub fn encrypt(recipient: &NamedRecipient, plaintext: &[u8]) -> Result<String> {
+    let encryptor = age::Encryptor::with_recipients(std::iter::once(
+        &recipient.recipient as &dyn age::Recipient,
+    ))
+    .map_err(|e| anyhow!("Failed to encrypt to {}: {}", recipient.name, e))?;
+
+    let mut armored = Vec::new();
+    let mut writer = encryptor.wrap_output(ArmoredWriter::wrap_output(
+        &mut armored,
+        Format::AsciiArmor,
+    )?)?;
+    writer.write_all(plaintext)?;
+    writer.finish()?.finish()?;
+
+    Ok(String::from_utf8(armored).expect("armored age output is ASCII"))
+}
+
+/// Whether the content is an age file, armored or binary
+pub fn is_age(content: &[u8]) -> bool {
+    let content = content.trim_ascii_start();
+    content.starts_with(BINARY_MAGIC) || content.starts_with(ARMOR_BEGIN)
+}
+
+/// Reads age identity files such as those written by `age-keygen` or
+/// `fractus keygen --age`
+pub fn read_identities(paths: &[PathBuf]) -> Result<Vec<Box<dyn age::Identity>>> {
+    let mut identities = Vec::new();
+    for path in paths {
+        let file = fs::File::open(path)
+            .with_context(|| format!("Failed to read identity file: {}", path.display()))?;
+        let file_identities = age::IdentityFile::from_buffer(BufReader::new(file))
+            .with_context(|| format!("{} is not an age identity file", path.display()))?
+            .into_identities()
+            .map_err(|e| anyhow!("Unsupported identity in {}: {}", path.display(), e))?;
+        identities.extend(file_identities);
+    }
+    Ok(identities)
+}
+
+/// Decrypts an age file with the first identity that opens it
+pub fn decrypt(
+    identities: &[Box<dyn age::Identity>],
+    content: &[u8],
+    source: &Path,
+) -> Result<Zeroizing<Vec<u8>>> {
+    if identities.is_empty() {
+        bail!(
+            "{} is encrypted with age; pass --identity to decrypt it",
+            source.display()
+        );
+    }
+
+    let decryptor = age::Decryptor::new_buffered(ArmoredReader::new(content))
+        .with_context(|| format!("{} is not a valid age file", source.display()))?;
+    let mut reader = match decryptor.decrypt(identities.iter().map(|identity| identity.as_ref())) {
+        Ok(reader) => reader,
+        Err(age::DecryptError::NoMatchingKeys) => bail!(
+            "{} is not encrypted to any of the given identities",
+            source.display()
+        ),
+        Err(e) => return Err(e).with_context(|| format!("Failed to decrypt {}", source.display())),
+    };
+
+    let mut plaintext = Zeroizing::new(Vec::new());
+    reader
+        .read_to_end(&mut plaintext)
+        .with_context(|| format!("Failed to decrypt {}", source.display()))?;
+    Ok(plaintext)
+}
@@ -0,0 +1,83 @@
+//! Armored share blocks pasted into recovery
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+const SECRET: &str = "pasted from an email thread";
+
+/// Splits into three armored blocks
+fn armored_blocks() -> Vec<String> {
+    let output = Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["split", "-n", "3", "-k", "2", "-f", "armor", "--stdout"])
+        .write_stdin(SECRET)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let blocks: Vec<String> = stdout
+        .split_inclusive("-----END FRACTUS SHARE-----\n")
+        .map(String::from)
+        .collect();
+    assert_eq!(blocks.len(), 3);
+    blocks
+}
+
+fn recover_stdin(input: String) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["recover", "--stdin"])
+        .write_stdin(input)
+        .assert()
+}
+
+#[test]
+fn blocks_pasted_together_recover_the_secret() {
+    let blocks = armored_blocks();
+    let pasted = format!(
+        "Here is my share:\n\n{}\n> and here is Carol's, forwarded\n  {}\nCheers\n",
+        blocks[0],
+        blocks[2].replace('\n', "\n  ")
+    );
+
+    recover_stdin(pasted).success().stdout(SECRET);
+}
+
+#[test]
+fn checksum_mismatch_names_the_block() {
+    let blocks = armored_blocks();
+    let mut lines: Vec<String> = blocks[1].lines().map(String::from).collect();
+    let body = lines
+        .iter()
+        .position(|line| line.is_empty())
+        .map(|blank| blank + 1)
+        .unwrap();
+    let typo = if lines[body].as_bytes()[2] == b'A' {
+        "B"
+    } else {
+        "A"
+    };
+    lines[body].replace_range(2..3, typo);
+
+    recover_stdin(format!("{}{}\n", blocks[0], lines.join("\n")))
+        .failure()
+        .stderr(predicate::str::contains(
+            "Armored share 2: Checksum mismatch; the share was altered or mistyped",
+        ));
+}
+
+#[test]
+fn block_without_end_line_is_rejected() {
+    let blocks = armored_blocks();
+    let truncated = blocks[1].replace("-----END FRACTUS SHARE-----\n", "");
+
+    // At the end of the input
+    recover_stdin(format!("{}{}", blocks[0], truncated))
+        .failure()
+        .stderr(predicate::str::contains("Armored share 2 has no END line"));
+
+    // Followed by another block
+    recover_stdin(format!("{}{}", truncated, blocks[2]))
+        .failure()
+        .stderr(predicate::str::contains("Armored share 1 has no END line"));
+}
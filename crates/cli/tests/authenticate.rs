@@ -0,0 +1,96 @@
+//! Cheater detection with authenticated shares
+
+mod common;
+
+use assert_cmd::Command;
+use common::{edit_share, split};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "pin the blame";
+
+/// Splits into four authenticated shares, tampers with the first and strips
+/// the authentication data of the second
+fn tampered_pool() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    split(4, 2, SECRET)
+        .args(["--authenticate", "-o"])
+        .arg(dir.path())
+        .assert()
+        .success();
+
+    edit_share(&dir.path().join("share-001.json"), |share| {
+        let y = share["y"][0].as_u64().unwrap();
+        share["y"][0] = (y ^ 1).into();
+    });
+    edit_share(&dir.path().join("share-002.json"), |share| {
+        share.as_object_mut().unwrap().remove("auth");
+    });
+    dir
+}
+
+#[test]
+fn stripping_auth_does_not_disable_cheater_detection() {
+    let dir = tampered_pool();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(SECRET)
+        .stderr(
+            predicate::str::contains("Ignoring share 2: it carries no authentication data").and(
+                predicate::str::contains("Ignoring shares altered after dealing: [1]"),
+            ),
+        );
+}
+
+#[test]
+fn allow_unauthenticated_uses_stripped_shares_unchecked() {
+    let dir = tampered_pool();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["recover", "--allow-unauthenticated"])
+        .arg(dir.path().join("share-001.json"))
+        .arg(dir.path().join("share-002.json"))
+        .arg(dir.path().join("share-003.json"))
+        .assert()
+        .success()
+        .stdout(SECRET)
+        .stderr(
+            predicate::str::contains("Share 2 carries no authentication data; using it unchecked")
+                .and(predicate::str::contains("Share 1 failed authentication")),
+        );
+}
+
+#[test]
+fn invalid_auth_data_rejects_only_that_share() {
+    let dir = TempDir::new().unwrap();
+    split(4, 2, SECRET)
+        .args(["--authenticate", "-o"])
+        .arg(dir.path())
+        .assert()
+        .success();
+    edit_share(&dir.path().join("share-001.json"), |share| {
+        share["auth"] = "00".into();
+    });
+    edit_share(&dir.path().join("share-002.json"), |share| {
+        share["auth"] = "not hex".into();
+    });
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(SECRET)
+        .stderr(
+            predicate::str::contains("Ignoring share 1: invalid authentication data").and(
+                predicate::str::contains("Ignoring share 2: invalid authentication data"),
+            ),
+        );
+}
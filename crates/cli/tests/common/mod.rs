@@ -0,0 +1,40 @@
+//! Helpers shared by the integration tests
+
+// Each test binary compiles this module but uses only some of the helpers
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+/// A `fractus split -n <shares> -k <threshold>` command reading `secret` from
+/// stdin, for the caller to add options to
+pub fn split(shares: u8, threshold: u8, secret: &str) -> Command {
+    let mut cmd = Command::cargo_bin("fractus").unwrap();
+    cmd.args(["split", "-n", &shares.to_string()])
+        .args(["-k", &threshold.to_string()])
+        .write_stdin(secret);
+    cmd
+}
+
+/// Runs `fractus keygen <kind> -o <path>`, where `kind` is `--signing` or
+/// `--age`
+pub fn keygen(kind: &str, path: &Path) {
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["keygen", kind, "-o"])
+        .arg(path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("written to"));
+}
+
+/// Rewrites a JSON share file after applying `edit` to it
+pub fn edit_share(path: &Path, edit: impl FnOnce(&mut serde_json::Value)) {
+    let mut share: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    edit(&mut share);
+    fs::write(path, share.to_string()).unwrap();
+}
@@ -0,0 +1,77 @@
+//! Dealer-free joint secret generation between three participants
+
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn deal(dir: &Path, id: u8) {
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["dkg", "deal", "-n", "3", "-k", "2", "-l", "16", "--id"])
+        .arg(id.to_string())
+        .arg("-o")
+        .arg(dir.join(format!("outbox-{}", id)))
+        .assert()
+        .success();
+}
+
+fn combine(dir: &Path, id: u8, dealers: &[u8]) -> Command {
+    let mut command = Command::cargo_bin("fractus").unwrap();
+    command
+        .args(["dkg", "combine", "-n", "3", "-k", "2", "--id"])
+        .arg(id.to_string())
+        .arg("-o")
+        .arg(dir.join(format!("share-{:03}.json", id)));
+    for dealer in dealers {
+        command.arg(dir.join(format!(
+            "outbox-{}/dkg-{:03}-to-{:03}.json",
+            dealer, dealer, id
+        )));
+    }
+    command
+}
+
+fn recover(dir: &Path, ids: [u8; 2]) -> Vec<u8> {
+    let output = Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .args(ids.map(|id| dir.join(format!("share-{:03}.json", id))))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    output.stdout
+}
+
+#[test]
+fn three_parties_deal_combine_and_recover() {
+    let dir = TempDir::new().unwrap();
+    for id in 1..=3 {
+        deal(dir.path(), id);
+    }
+    for id in 1..=3 {
+        combine(dir.path(), id, &[1, 2, 3]).assert().success();
+    }
+
+    // Every pair of participants recovers the same 16-byte secret
+    let secret = recover(dir.path(), [1, 2]);
+    assert_eq!(secret.len(), 16);
+    assert_eq!(recover(dir.path(), [2, 3]), secret);
+    assert_eq!(recover(dir.path(), [1, 3]), secret);
+}
+
+#[test]
+fn combine_requires_a_message_from_every_participant() {
+    let dir = TempDir::new().unwrap();
+    for id in 1..=3 {
+        deal(dir.path(), id);
+    }
+
+    combine(dir.path(), 1, &[1, 2])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Missing messages from participants: 3",
+        ));
+}
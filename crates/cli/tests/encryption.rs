@@ -0,0 +1,205 @@
+//! Share files encrypted under per-custodian passphrases
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use common::{edit_share, split};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "sealed with three passphrases";
+
+fn passphrase_file(dir: &Path, name: &str, passphrases: &[&str]) -> PathBuf {
+    let path = dir.join(name);
+    fs::write(&path, passphrases.join("\n")).unwrap();
+    path
+}
+
+/// Splits into three shares with metadata, each sealed under its own passphrase
+fn sealed_shares() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let passphrases = passphrase_file(dir.path(), "passphrases.txt", &["red", "green", "blue"]);
+    split(3, 2, SECRET)
+        .args(["--include-metadata", "--encrypt-shares"])
+        .arg("--share-passphrases")
+        .arg(passphrases)
+        .arg("-o")
+        .arg(dir.path().join("shares"))
+        .assert()
+        .success();
+    dir
+}
+
+#[test]
+fn sealed_shares_open_with_their_passphrases() {
+    let dir = sealed_shares();
+    let share = fs::read_to_string(dir.path().join("shares/share-001.json")).unwrap();
+    assert!(share.contains("fractus-encrypted-share"));
+    assert!(!share.contains("\"y\""));
+
+    // Passphrases are tried on every share, so their order does not matter
+    let passphrases = passphrase_file(dir.path(), "custodians.txt", &["blue", "red"]);
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg("--share-passphrases")
+        .arg(passphrases)
+        .arg(dir.path().join("shares/share-001.json"))
+        .arg(dir.path().join("shares/share-003.json"))
+        .assert()
+        .success()
+        .stdout(SECRET);
+}
+
+#[test]
+fn wrong_passphrase_leaves_share_locked() {
+    let dir = sealed_shares();
+    let passphrases = passphrase_file(dir.path(), "custodians.txt", &["red", "yellow"]);
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg("--share-passphrases")
+        .arg(passphrases)
+        .arg(dir.path().join("shares/share-001.json"))
+        .arg(dir.path().join("shares/share-002.json"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "share-002.json: no passphrase in the passphrase file decrypts it",
+        ));
+}
+
+#[test]
+fn open_rejects_tampered_metadata() {
+    // The header field, its forged value and the x-coordinate it then shows
+    for (field, value, x) in [("x", 9, 9), ("share_len", 4, 1), ("threshold", 1, 1)] {
+        let dir = sealed_shares();
+        edit_share(&dir.path().join("shares/share-001.json"), |share| {
+            share[field] = value.into();
+        });
+
+        Command::cargo_bin("fractus")
+            .unwrap()
+            .arg("recover")
+            .arg("--share-passphrases")
+            .arg(dir.path().join("passphrases.txt"))
+            .arg(dir.path().join("shares"))
+            .assert()
+            .success()
+            .stdout(SECRET)
+            .stderr(predicate::str::contains(format!(
+                "The metadata of encrypted share {} does not match its contents",
+                x
+            )));
+    }
+}
+
+#[test]
+fn info_shows_the_metadata_of_locked_shares() {
+    let dir = sealed_shares();
+    let passphrases = passphrase_file(dir.path(), "custodians.txt", &["green"]);
+
+    let output = Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["info", "--output-format", "json", "--share-passphrases"])
+        .arg(&passphrases)
+        .arg(dir.path().join("shares"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    // Locked shares report the length from their header, which includes the
+    // 4-byte checksum
+    assert_eq!(info["y_length"], SECRET.len() + 4);
+    for share in info["shares"].as_array().unwrap() {
+        let locked = share["x_coordinate"] != 2;
+        assert_eq!(
+            share["encryption"],
+            if locked { "locked" } else { "decrypted" }
+        );
+        assert_eq!(share["threshold"], 2);
+        assert_eq!(share["total_shares"], 3);
+        assert!(share["set_id"].is_string());
+        assert_eq!(share["fingerprint"].is_null(), locked);
+    }
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("info")
+        .arg("--share-passphrases")
+        .arg(&passphrases)
+        .arg(dir.path().join("shares"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Encrypted shares: 3 (2 not decrypted)",
+        ));
+}
+
+#[test]
+fn info_without_a_passphrase_source_shows_shares_locked() {
+    let dir = sealed_shares();
+
+    // No passphrase file, and stdin is not a terminal to prompt on
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("info")
+        .arg(dir.path().join("shares/share-001.json"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Encrypted shares: 1 (1 not decrypted)",
+        ));
+
+    let output = Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["info", "--output-format", "json"])
+        .arg(dir.path().join("shares"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(info["total_shares"], 3);
+    assert_eq!(info["inferred_threshold"], 2);
+    for share in info["shares"].as_array().unwrap() {
+        assert_eq!(share["encryption"], "locked");
+    }
+}
+
+#[test]
+fn info_warns_about_files_it_cannot_read_in_a_directory() {
+    let dir = sealed_shares();
+    fs::write(dir.path().join("shares/notes.json"), "not a share").unwrap();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("info")
+        .arg(dir.path().join("shares"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Total shares: 3"))
+        .stderr(predicate::str::contains("Skipping").and(predicate::str::contains("notes.json")));
+}
+
+#[test]
+fn split_requires_one_passphrase_per_share() {
+    let dir = TempDir::new().unwrap();
+    for passphrases in [&["red", "green"][..], &["red", "green", "blue", "cyan"]] {
+        let path = passphrase_file(dir.path(), "passphrases.txt", passphrases);
+        split(3, 2, SECRET)
+            .args(["--encrypt-shares", "--share-passphrases"])
+            .arg(&path)
+            .arg("-o")
+            .arg(dir.path().join("shares"))
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "has {} passphrases but there are 3 shares",
+                passphrases.len()
+            )));
+    }
+}
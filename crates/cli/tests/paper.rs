@@ -0,0 +1,129 @@
+//! Printable paper sheets of shares
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "correct horse battery staple";
+
+/// The share lines of a sheet, as a custodian would type them back in
+fn typed_share(sheet: &str) -> String {
+    let table = &sheet[sheet.find("<table class=\"groups\">").unwrap()..];
+    let table = &table[..table.find("</table>").unwrap()];
+    table
+        .split("<tr>")
+        .skip(1)
+        .map(|row| {
+            row.split("<td")
+                .skip(1)
+                .map(|cell| &cell[cell.find('>').unwrap() + 1..cell.find("</td>").unwrap()])
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn typed_paper_sheets_recover_the_secret() {
+    let dir = TempDir::new().unwrap();
+    let sheets = dir.path().join("sheets");
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["split", "-n", "3", "-k", "2", "--paper"])
+        .arg(&sheets)
+        .args(["--custodian", "Alice", "--custodian", "Bob & Co"])
+        .args(["--custodian", "Carol"])
+        .write_stdin(SECRET)
+        .assert()
+        .success();
+
+    let mut typed = Vec::new();
+    for i in 1..=3 {
+        let sheet = fs::read_to_string(sheets.join(format!("share-{:03}.html", i))).unwrap();
+        assert!(sheet.contains("<svg"));
+        assert!(sheet.contains(&format!("Secret share {} of 3", i)));
+        let path = dir.path().join(format!("typed-{}.txt", i));
+        fs::write(&path, typed_share(&sheet)).unwrap();
+        typed.push((sheet, path));
+    }
+    assert!(typed[1].0.contains("Bob &amp; Co"));
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(&typed[0].1)
+        .arg(&typed[2].1)
+        .assert()
+        .success()
+        .stdout(SECRET);
+
+    // The fingerprint printed on the sheet matches the typed share
+    let sheet = &typed[0].0;
+    let label = "Fingerprint</td><td class=\"mono\">";
+    let start = sheet.find(label).unwrap() + label.len();
+    let fingerprint = &sheet[start..start + 19];
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["info", "--detailed"])
+        .arg(&typed[0].1)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(fingerprint));
+}
+
+#[test]
+fn mistyped_sheet_line_is_named() {
+    let dir = TempDir::new().unwrap();
+    let sheets = dir.path().join("sheets");
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["split", "-n", "2", "-k", "2", "--paper"])
+        .arg(&sheets)
+        .write_stdin(SECRET)
+        .assert()
+        .success();
+
+    let mut paths = Vec::new();
+    for i in 1..=2 {
+        let sheet = fs::read_to_string(sheets.join(format!("share-{:03}.html", i))).unwrap();
+        let path = dir.path().join(format!("typed-{}.txt", i));
+        fs::write(&path, typed_share(&sheet)).unwrap();
+        paths.push(path);
+    }
+
+    // Miscopy the checksum of the second line of the first sheet
+    let typed = fs::read_to_string(&paths[0]).unwrap();
+    let mut lines: Vec<String> = typed.lines().map(String::from).collect();
+    let total = lines.len();
+    let last = lines[1].pop().unwrap();
+    lines[1].push(if last == '7' { '8' } else { '7' });
+    fs::write(&paths[0], lines.join("\n")).unwrap();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .args(&paths)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "Line 2/{}: checksum mismatch",
+            total
+        )));
+}
+
+#[test]
+fn paper_requires_one_custodian_per_share() {
+    let dir = TempDir::new().unwrap();
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["split", "-n", "3", "-k", "2", "--custodian", "Alice"])
+        .arg("--paper")
+        .arg(dir.path())
+        .write_stdin(SECRET)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Got 1 custodians for 3 shares"));
+}
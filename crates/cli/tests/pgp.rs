@@ -0,0 +1,142 @@
+//! Round trips of shares encrypted to OpenPGP keys generated on the fly
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use pgp::composed::{
+    ArmorOptions, EncryptionCaps, KeyType, SecretKeyParamsBuilder, SignedPublicKey,
+    SubkeyParamsBuilder,
+};
+use pgp::crypto::ecc_curve::ECCCurve;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "correct horse battery staple";
+
+/// Writes an Ed25519 key with a Curve25519 encryption subkey as
+/// `<name>.asc` and `<name>-secret.asc`, returning both paths
+fn generate_key(dir: &Path, name: &str) -> (PathBuf, PathBuf) {
+    let mut subkey = SubkeyParamsBuilder::default();
+    subkey
+        .key_type(KeyType::ECDH(ECCCurve::Curve25519Legacy))
+        .can_sign(false)
+        .can_encrypt(EncryptionCaps::All)
+        .can_authenticate(false);
+
+    let mut params = SecretKeyParamsBuilder::default();
+    params
+        .key_type(KeyType::Ed25519Legacy)
+        .can_certify(true)
+        .can_sign(true)
+        .can_encrypt(EncryptionCaps::None)
+        .primary_user_id(format!("{} <{}@example.com>", name, name))
+        .subkeys(vec![subkey.build().unwrap()]);
+    let secret_key = params
+        .build()
+        .unwrap()
+        .generate(rand::thread_rng())
+        .unwrap();
+    let public_key = SignedPublicKey::from(secret_key.clone());
+
+    let public_path = dir.join(format!("{}.asc", name));
+    let secret_path = dir.join(format!("{}-secret.asc", name));
+    fs::write(
+        &public_path,
+        public_key
+            .to_armored_string(ArmorOptions::default())
+            .unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        &secret_path,
+        secret_key
+            .to_armored_string(ArmorOptions::default())
+            .unwrap(),
+    )
+    .unwrap();
+    (public_path, secret_path)
+}
+
+/// Splits SECRET into one share per custodian key, returning the share directory
+fn split(dir: &TempDir, public_keys: &[&Path]) -> PathBuf {
+    let shares = dir.path().join("shares");
+    let mut cmd = common::split(public_keys.len() as u8, 2, SECRET);
+    cmd.arg("-o").arg(&shares);
+    for key in public_keys {
+        cmd.arg("--pgp-recipient").arg(key);
+    }
+    cmd.assert().success();
+    shares
+}
+
+#[test]
+fn shares_round_trip_through_pgp_keys() {
+    let dir = TempDir::new().unwrap();
+    let (alice_pub, alice_sec) = generate_key(dir.path(), "alice");
+    let (bob_pub, bob_sec) = generate_key(dir.path(), "bob");
+    let (carol_pub, _) = generate_key(dir.path(), "carol");
+    let shares = split(&dir, &[&alice_pub, &bob_pub, &carol_pub]);
+
+    let alice_share = shares.join("share-001-alice.asc");
+    let content = fs::read_to_string(&alice_share).unwrap();
+    assert!(content.starts_with("-----BEGIN PGP MESSAGE-----"));
+    assert!(!content.contains("\"y\""));
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(&alice_share)
+        .arg(shares.join("share-002-bob.asc"))
+        .arg("--pgp-key")
+        .arg(&alice_sec)
+        .arg("--pgp-key")
+        .arg(&bob_sec)
+        .assert()
+        .success()
+        .stdout(SECRET);
+}
+
+#[test]
+fn recover_reads_pgp_shares_from_a_directory() {
+    let dir = TempDir::new().unwrap();
+    let (alice_pub, alice_sec) = generate_key(dir.path(), "alice");
+    let (bob_pub, bob_sec) = generate_key(dir.path(), "bob");
+    let shares = split(&dir, &[&alice_pub, &bob_pub]);
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(&shares)
+        .arg("--pgp-key")
+        .arg(&alice_sec)
+        .arg("--pgp-key")
+        .arg(&bob_sec)
+        .assert()
+        .success()
+        .stdout(SECRET);
+}
+
+#[test]
+fn recover_fails_without_the_right_key() {
+    let dir = TempDir::new().unwrap();
+    let (alice_pub, alice_sec) = generate_key(dir.path(), "alice");
+    let (bob_pub, _) = generate_key(dir.path(), "bob");
+    let (_, mallory_sec) = generate_key(dir.path(), "mallory");
+    let shares = split(&dir, &[&alice_pub, &bob_pub]);
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(shares.join("share-001-alice.asc"))
+        .arg(shares.join("share-002-bob.asc"))
+        .arg("--pgp-key")
+        .arg(&alice_sec)
+        .arg("--pgp-key")
+        .arg(&mallory_sec)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to decrypt"));
+}
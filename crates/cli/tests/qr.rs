@@ -0,0 +1,86 @@
+//! Round trips of shares through QR code images
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "correct horse battery staple";
+
+#[test]
+fn shares_round_trip_through_qr_images() {
+    let dir = TempDir::new().unwrap();
+    let shares = dir.path().join("shares");
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["split", "-n", "3", "-k", "2", "-f", "qr", "-o"])
+        .arg(&shares)
+        .write_stdin(SECRET)
+        .assert()
+        .success();
+
+    for i in 1..=3 {
+        assert!(shares.join(format!("share-{:03}.png", i)).is_file());
+        let svg = fs::read_to_string(shares.join(format!("share-{:03}.svg", i))).unwrap();
+        assert!(svg.contains("<svg"));
+    }
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(shares.join("share-001.png"))
+        .arg(shares.join("share-003.png"))
+        .assert()
+        .success()
+        .stdout(SECRET);
+}
+
+#[test]
+fn recover_reads_qr_images_from_a_directory() {
+    let dir = TempDir::new().unwrap();
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["split", "-n", "2", "-k", "2", "-f", "qr", "-o"])
+        .arg(dir.path())
+        .write_stdin(SECRET)
+        .assert()
+        .success();
+
+    // The SVG copies sit next to the PNG images and are skipped
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(SECRET);
+}
+
+#[test]
+fn recover_rejects_images_without_a_qr_code() {
+    let dir = TempDir::new().unwrap();
+    let blank = dir.path().join("blank.png");
+    image::GrayImage::from_pixel(64, 64, image::Luma([255]))
+        .save(&blank)
+        .unwrap();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(&blank)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No QR code found"));
+}
+
+#[test]
+fn stdout_renders_qr_codes_as_terminal_blocks() {
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["split", "-n", "2", "-k", "2", "-f", "qr", "--stdout"])
+        .write_stdin(SECRET)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Share 2").and(predicate::str::contains("\u{2588}")));
+}
@@ -0,0 +1,109 @@
+//! Shares encrypted to custodians' age public keys
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use common::split;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "for the custodians' eyes only";
+const CUSTODIANS: [&str; 3] = ["alice", "bob", "carol"];
+
+/// Writes an age identity to `<name>.txt` and returns its public key
+fn keygen(dir: &Path, name: &str) -> String {
+    common::keygen("--age", &dir.join(format!("{}.txt", name)));
+    let public = fs::read_to_string(dir.join(format!("{}.pub", name))).unwrap();
+    assert!(public.starts_with("age1"));
+    public.trim().to_string()
+}
+
+/// Splits into one share per custodian, each encrypted to their key
+fn encrypted_shares() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let mut cmd = split(3, 2, SECRET);
+    for name in CUSTODIANS {
+        let public = keygen(dir.path(), name);
+        cmd.arg("--recipient").arg(format!("{}={}", name, public));
+    }
+    cmd.arg("-o")
+        .arg(dir.path().join("shares"))
+        .assert()
+        .success();
+    keygen(dir.path(), "mallory");
+    dir
+}
+
+#[test]
+fn custodians_recover_with_their_identities() {
+    let dir = encrypted_shares();
+    let share = fs::read_to_string(dir.path().join("shares/share-001-alice.age")).unwrap();
+    assert!(share.starts_with("-----BEGIN AGE ENCRYPTED FILE-----"));
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg("--identity")
+        .arg(dir.path().join("alice.txt"))
+        .arg("--identity")
+        .arg(dir.path().join("carol.txt"))
+        .arg(dir.path().join("shares/share-001-alice.age"))
+        .arg(dir.path().join("shares/share-003-carol.age"))
+        .assert()
+        .success()
+        .stdout(SECRET);
+}
+
+#[test]
+fn directory_reports_shares_the_identities_cannot_open() {
+    let dir = encrypted_shares();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg("--identity")
+        .arg(dir.path().join("alice.txt"))
+        .arg("--identity")
+        .arg(dir.path().join("bob.txt"))
+        .arg(dir.path().join("shares"))
+        .assert()
+        .success()
+        .stdout(SECRET)
+        .stderr(
+            predicate::str::contains(
+                "share-003-carol.age is not encrypted to any of the given identities",
+            )
+            .and(predicate::str::contains("share-001-alice.age").not()),
+        );
+}
+
+#[test]
+fn wrong_identity_names_the_share() {
+    let dir = encrypted_shares();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg("--identity")
+        .arg(dir.path().join("mallory.txt"))
+        .arg(dir.path().join("shares/share-002-bob.age"))
+        .arg(dir.path().join("shares/share-003-carol.age"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "share-002-bob.age is not encrypted to any of the given identities",
+        ));
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(dir.path().join("shares/share-002-bob.age"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "share-002-bob.age is encrypted with age; pass --identity to decrypt it",
+        ));
+}
@@ -0,0 +1,205 @@
+//! Dealer-signed shares, checked against a trusted dealer key
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use common::{edit_share, split};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "only the dealer may deal";
+
+fn keygen(path: &Path) {
+    common::keygen("--signing", path);
+}
+
+fn split_signed(dir: &Path, key: &Path, extra: &[&str]) {
+    split(7, 2, SECRET)
+        .arg("--sign-key")
+        .arg(key)
+        .args(extra)
+        .arg("-o")
+        .arg(dir)
+        .assert()
+        .success();
+}
+
+/// Signs seven shares, then alters the first five in a different way each
+/// and leaves shares 6 and 7 intact
+fn forged_pool() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    keygen(&dir.path().join("dealer.key"));
+    keygen(&dir.path().join("other.key"));
+    let shares = dir.path().join("shares");
+    split_signed(&shares, &dir.path().join("dealer.key"), &[]);
+    let other = dir.path().join("other");
+    split_signed(&other, &dir.path().join("other.key"), &[]);
+
+    edit_share(&shares.join("share-001.json"), |share| {
+        let y = share["y"][0].as_u64().unwrap();
+        share["y"][0] = (y ^ 1).into();
+    });
+    edit_share(&shares.join("share-002.json"), |share| {
+        share["threshold"] = 3.into();
+    });
+    edit_share(&shares.join("share-003.json"), |share| {
+        share["set_id"] = "00".repeat(16).into();
+    });
+    edit_share(&shares.join("share-004.json"), |share| {
+        share.as_object_mut().unwrap().remove("signature");
+    });
+    fs::copy(other.join("share-005.json"), shares.join("share-005.json")).unwrap();
+    dir
+}
+
+#[test]
+fn keygen_writes_a_key_pair_and_keeps_existing_keys() {
+    let dir = TempDir::new().unwrap();
+    let key = dir.path().join("dealer.key");
+    keygen(&key);
+
+    let secret = fs::read_to_string(&key).unwrap();
+    let public = fs::read_to_string(dir.path().join("dealer.pub")).unwrap();
+    assert_eq!(hex::decode(secret.trim()).unwrap().len(), 32);
+    assert_eq!(hex::decode(public.trim()).unwrap().len(), 32);
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["keygen", "--signing", "-o"])
+        .arg(&key)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+    assert_eq!(fs::read_to_string(&key).unwrap(), secret);
+}
+
+#[test]
+fn recover_drops_shares_the_trusted_dealer_did_not_sign() {
+    let dir = forged_pool();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["recover", "--trusted-dealer"])
+        .arg(dir.path().join("dealer.pub"))
+        .arg(dir.path().join("shares"))
+        .assert()
+        .success()
+        .stdout(SECRET)
+        .stderr(
+            predicate::str::contains(
+                "Ignoring share 1: signature does not match the trusted dealer",
+            )
+            .and(predicate::str::contains(
+                "Ignoring share 2: signature does not match the trusted dealer",
+            ))
+            .and(predicate::str::contains(
+                "Ignoring share 3: signature does not match the trusted dealer",
+            ))
+            .and(predicate::str::contains("Ignoring share 4: not signed"))
+            .and(predicate::str::contains(
+                "Ignoring share 5: signature does not match the trusted dealer",
+            ))
+            .and(predicate::str::contains("Ignoring share 6").not())
+            .and(predicate::str::contains("Ignoring share 7").not()),
+        );
+}
+
+#[test]
+fn recover_fails_without_a_validly_signed_share() {
+    let dir = forged_pool();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["recover", "--trusted-dealer"])
+        .arg(dir.path().join("dealer.pub"))
+        .arg(dir.path().join("shares/share-001.json"))
+        .arg(dir.path().join("shares/share-004.json"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No shares carry a valid signature from the trusted dealer",
+        ));
+}
+
+#[test]
+fn signature_covers_authentication_data() {
+    let dir = TempDir::new().unwrap();
+    keygen(&dir.path().join("dealer.key"));
+    let shares = dir.path().join("shares");
+    split_signed(&shares, &dir.path().join("dealer.key"), &["--authenticate"]);
+
+    // Swapping in another share's MAC keys breaks the signature
+    let auth: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(shares.join("share-002.json")).unwrap()).unwrap();
+    edit_share(&shares.join("share-001.json"), |share| {
+        share["auth"] = auth["auth"].clone();
+    });
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["recover", "--trusted-dealer"])
+        .arg(dir.path().join("dealer.pub"))
+        .arg(&shares)
+        .assert()
+        .success()
+        .stdout(SECRET)
+        .stderr(predicate::str::contains(
+            "Ignoring share 1: signature does not match the trusted dealer",
+        ));
+}
+
+#[test]
+fn info_reports_signature_status() {
+    let dir = forged_pool();
+
+    let output = Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["info", "--output-format", "json", "--trusted-dealer"])
+        .arg(dir.path().join("dealer.pub"))
+        .arg(dir.path().join("shares"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let mut statuses: Vec<(u64, String)> = info["shares"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|share| {
+            (
+                share["x_coordinate"].as_u64().unwrap(),
+                share["signature"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    statuses.sort();
+    assert_eq!(
+        statuses,
+        [
+            (1, "invalid"),
+            (2, "invalid"),
+            (3, "invalid"),
+            (4, "unsigned"),
+            (5, "invalid"),
+            (6, "valid"),
+            (7, "valid"),
+        ]
+        .map(|(x, status)| (x, status.to_string()))
+    );
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("info")
+        .arg("--trusted-dealer")
+        .arg(dir.path().join("dealer.pub"))
+        .arg(dir.path().join("shares"))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Signed by trusted dealer: 2 of 7")
+                .and(predicate::str::contains("Share 4: signature is unsigned")),
+        );
+}
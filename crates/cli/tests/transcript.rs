@@ -0,0 +1,132 @@
+//! Transcribed shares, typed in from files, stdin and the interactive prompt
+
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "a secret long enough to need a few lines of transcription";
+
+fn split(dir: &Path) -> Vec<String> {
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["split", "-n", "3", "-k", "2", "-f", "transcript", "-o"])
+        .arg(dir)
+        .write_stdin(SECRET)
+        .assert()
+        .success();
+    (1..=3)
+        .map(|i| fs::read_to_string(dir.join(format!("share-{:03}.b32", i))).unwrap())
+        .collect()
+}
+
+/// Miscopies the last character of a line, which is part of its checksum and
+/// so always caught
+fn mistype(line: &str) -> String {
+    let (rest, last) = line.split_at(line.len() - 1);
+    format!("{}{}", rest, if last == "7" { "8" } else { "7" })
+}
+
+#[test]
+fn transcribed_shares_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let shares = split(dir.path());
+    assert!(
+        shares[0]
+            .lines()
+            .all(|line| line.split_whitespace().count() <= 6)
+    );
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(dir.path().join("share-001.b32"))
+        .arg(dir.path().join("share-003.b32"))
+        .assert()
+        .success()
+        .stdout(SECRET);
+
+    // Lower case and look-alike letters are accepted, and --stdin needs no files
+    let typed = format!(
+        "{}\n{}",
+        shares[1].to_lowercase().replace('0', "o"),
+        shares[2]
+    );
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["recover", "--stdin"])
+        .write_stdin(typed)
+        .assert()
+        .success()
+        .stdout(SECRET);
+}
+
+#[test]
+fn recover_names_the_mistyped_line() {
+    let dir = TempDir::new().unwrap();
+    let shares = split(dir.path());
+    let mut lines: Vec<String> = shares[0].lines().map(String::from).collect();
+    lines[1] = mistype(&lines[1]);
+    let total = lines.len();
+    fs::write(dir.path().join("share-001.b32"), lines.join("\n")).unwrap();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("recover")
+        .arg(dir.path().join("share-001.b32"))
+        .arg(dir.path().join("share-002.b32"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "Line 2/{}: checksum mismatch",
+            total
+        )));
+}
+
+#[test]
+fn interactive_prompt_asks_again_for_a_wrong_line() {
+    let dir = TempDir::new().unwrap();
+    let shares = split(dir.path());
+    let lines: Vec<&str> = shares[0].lines().collect();
+
+    // The second line is mistyped, then skipped, then typed correctly
+    let mut typed = vec![lines[0].to_string(), mistype(lines[1])];
+    typed.push(lines[2].to_string());
+    typed.extend(lines[1..].iter().map(|line| line.to_string()));
+    typed.extend(shares[1].lines().map(String::from));
+    typed.push(String::new());
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["recover", "--interactive"])
+        .write_stdin(typed.join("\n"))
+        .assert()
+        .success()
+        .stdout(SECRET)
+        .stderr(
+            predicate::str::contains(format!("Line 2/{}: checksum mismatch", lines.len()))
+                .and(predicate::str::contains(format!(
+                    "Expected line 2/{}, got line 3/{}",
+                    lines.len(),
+                    lines.len()
+                )))
+                .and(predicate::str::contains("Share 2 accepted")),
+        );
+}
+
+#[test]
+fn interactive_prompt_reports_an_unfinished_share() {
+    let dir = TempDir::new().unwrap();
+    let shares = split(dir.path());
+    let first = shares[0].lines().next().unwrap();
+
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .args(["recover", "--interactive"])
+        .write_stdin(format!("{}\n", first))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Share 1 is incomplete: line 2/"));
+}
@@ -0,0 +1,97 @@
+//! Verifiable shares checked against the dealer's commitments
+
+mod common;
+
+use std::path::Path;
+
+use assert_cmd::Command;
+use common::{edit_share, split};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SECRET: &str = "verify before you trust";
+
+/// Deals four verifiable 2-of-4 shares with the given scheme
+fn dealt_shares(scheme: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    split(4, 2, SECRET)
+        .args(["--vss", scheme, "-o"])
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("commitments.json"));
+    dir
+}
+
+/// Flips the lowest bit of the share's first scalar, which keeps it canonical
+fn tamper(path: &Path) {
+    edit_share(path, |share| {
+        let mut bytes = hex::decode(share["share"].as_str().unwrap()).unwrap();
+        bytes[1] ^= 1;
+        share["share"] = hex::encode(bytes).into();
+    });
+}
+
+fn vss_verify(dir: &Path, share: &str) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("fractus")
+        .unwrap()
+        .arg("vss-verify")
+        .arg("--commitments")
+        .arg(dir.join("commitments.json"))
+        .arg(dir.join(share))
+        .assert()
+}
+
+#[test]
+fn every_dealt_share_matches_the_commitments() {
+    for scheme in ["feldman", "pedersen"] {
+        let dir = dealt_shares(scheme);
+        for x in 1..=4 {
+            vss_verify(dir.path(), &format!("share-{:03}.json", x))
+                .success()
+                .stdout(predicate::str::contains(format!("Share {} (", x)))
+                .stdout(predicate::str::contains("matches the commitments"));
+        }
+
+        Command::cargo_bin("fractus")
+            .unwrap()
+            .arg("recover")
+            .arg("--commitments")
+            .arg(dir.path().join("commitments.json"))
+            .arg(dir.path().join("share-002.json"))
+            .arg(dir.path().join("share-004.json"))
+            .assert()
+            .success()
+            .stdout(SECRET);
+    }
+}
+
+#[test]
+fn tampered_share_is_rejected_and_skipped() {
+    for scheme in ["feldman", "pedersen"] {
+        let dir = dealt_shares(scheme);
+        tamper(&dir.path().join("share-002.json"));
+
+        vss_verify(dir.path(), "share-002.json")
+            .failure()
+            .stdout(predicate::str::contains("❌ Share 2"))
+            .stderr(predicate::str::contains(
+                "1 of 1 shares do not match the dealer's commitments",
+            ));
+
+        Command::cargo_bin("fractus")
+            .unwrap()
+            .arg("recover")
+            .arg("--commitments")
+            .arg(dir.path().join("commitments.json"))
+            .arg(dir.path())
+            .assert()
+            .success()
+            .stdout(SECRET)
+            .stderr(
+                predicate::str::contains("Ignoring")
+                    .and(predicate::str::contains("share-002.json"))
+                    .and(predicate::str::contains("share-001.json").not()),
+            );
+    }
+}
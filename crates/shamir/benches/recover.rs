@@ -0,0 +1,85 @@
+//! Recovery benchmarks.
+//!
+//! `per_byte_basis` reproduces the original interpolation, which rebuilt the
+//! Lagrange basis (and its field inversions) for every byte position. It is kept
+//! here as a reference point for the precomputed-weight implementation used by
+//! `Shamir::recover`.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use fractus_shamir::gf256::GF256;
+use fractus_shamir::{Shamir, Share, poly};
+use rand_chacha::rand_core::SeedableRng;
+use std::hint::black_box;
+
+const THRESHOLD: u8 = 10;
+const SIZES: [usize; 3] = [1 << 10, 64 << 10, 1 << 20];
+
+fn make_shares(len: usize) -> Vec<Share> {
+    let shamir = Shamir::new(THRESHOLD).unwrap();
+    let mut rng = rand_chacha::ChaCha8Rng::from_seed([0x42; 32]);
+    let secret: Vec<u8> = (0..len).map(|i| (i * 31 + 7) as u8).collect();
+    shamir
+        .split_with_rng(&secret, &mut rng)
+        .unwrap()
+        .take(THRESHOLD as usize)
+        .collect()
+}
+
+fn per_byte_basis(shares: &[Share]) -> Vec<u8> {
+    (0..shares[0].y.len())
+        .map(|byte_index| {
+            shares
+                .iter()
+                .map(|share_j| {
+                    let basis = shares
+                        .iter()
+                        .filter(|share_i| share_i.x != share_j.x)
+                        .map(|share_i| share_i.x / (share_j.x - share_i.x))
+                        .product::<GF256>();
+                    basis * share_j.y[byte_index]
+                })
+                .sum::<GF256>()
+                .value()
+        })
+        .collect()
+}
+
+fn bench_interpolate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("interpolate");
+    group.sample_size(10);
+
+    for len in SIZES {
+        let shares = make_shares(len);
+        group.throughput(Throughput::Bytes(len as u64));
+
+        group.bench_with_input(BenchmarkId::new("per_byte_basis", len), &shares, |b, s| {
+            b.iter(|| per_byte_basis(black_box(s)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("precomputed_weights", len),
+            &shares,
+            |b, s| b.iter(|| poly::interpolate(black_box(s))),
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_recover(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recover");
+    group.sample_size(10);
+    let shamir = Shamir::new(THRESHOLD).unwrap();
+
+    for len in SIZES {
+        let shares = make_shares(len);
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(len), &shares, |b, s| {
+            b.iter(|| shamir.recover(black_box(s)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_interpolate, bench_recover);
+criterion_main!(benches);
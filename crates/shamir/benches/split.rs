@@ -0,0 +1,61 @@
+//! Split and GF(256) kernel benchmarks.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use fractus_shamir::Shamir;
+use fractus_shamir::gf256::{GF256, mul_add_slice};
+use rand_chacha::rand_core::SeedableRng;
+use std::hint::black_box;
+
+const SIZES: [usize; 3] = [1 << 10, 64 << 10, 1 << 20];
+
+fn bench_mul_add_slice(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul_add_slice");
+
+    for len in SIZES {
+        let src: Vec<u8> = (0..len).map(|i| (i * 31 + 7) as u8).collect();
+        let mut dst = vec![0u8; len];
+        group.throughput(Throughput::Bytes(len as u64));
+
+        group.bench_with_input(BenchmarkId::new("scalar", len), &src, |b, src| {
+            b.iter(|| {
+                for (d, &s) in dst.iter_mut().zip(src) {
+                    *d ^= (GF256(s) * black_box(GF256(0x53))).0;
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("vectorized", len), &src, |b, src| {
+            b.iter(|| mul_add_slice(&mut dst, src, black_box(GF256(0x53))))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_split_into(c: &mut Criterion) {
+    let mut group = c.benchmark_group("split_into");
+    group.sample_size(10);
+    let shamir = Shamir::new(3).unwrap();
+    let xs: Vec<GF256> = (1..=5).map(GF256).collect();
+
+    for len in SIZES {
+        let secret: Vec<u8> = (0..len).map(|i| (i * 31 + 7) as u8).collect();
+        let share_len = Shamir::share_len(len);
+        let mut buffer = vec![0u8; xs.len() * share_len];
+        group.throughput(Throughput::Bytes(len as u64));
+
+        group.bench_with_input(BenchmarkId::from_parameter(len), &secret, |b, secret| {
+            let mut rng = rand_chacha::ChaCha8Rng::from_seed([0x42; 32]);
+            b.iter(|| {
+                let mut outputs: Vec<&mut [u8]> = buffer.chunks_mut(share_len).collect();
+                shamir
+                    .split_into(black_box(secret), &xs, &mut rng, &mut outputs)
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_mul_add_slice, bench_split_into);
+criterion_main!(benches);
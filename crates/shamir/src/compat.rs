@@ -0,0 +1,205 @@
+//! Shares written by other Shamir implementations.
+//!
+//! [`Shamir::recover_in`](crate::Shamir::recover_in) does the arithmetic of
+//! another implementation's field, but the shares themselves are also laid out
+//! differently. These functions turn them into [`Share`]s:
+//!
+//! * libgfshare's `gfsplit` writes each share to a file named after the input
+//!   with the share's x-coordinate as a three-digit extension, such as
+//!   `secret.txt.042`, holding only the y bytes. Recover with
+//!   [`Gf2p8Gfshare`](crate::gf256::Gf2p8Gfshare).
+//! * bc-shamir, the Blockchain Commons library under SSKR wallet backups,
+//!   numbers shares from 0 and keeps the secret at x = 255 rather than 0. As
+//!   addition in GF(2^8) is XOR, adding 255 to every x-coordinate moves the
+//!   secret to 0 without changing the degree of the polynomial. Recover with
+//!   [`Gf2p8Aes`](crate::gf256::Gf2p8Aes).
+//! * sharks and blahaj serialize a share as its x-coordinate followed by its
+//!   y bytes, as [`Share::to_bytes`] does, so [`Share::from_bytes`] reads them
+//!   directly. Recover with [`Gf2p8Gfshare`](crate::gf256::Gf2p8Gfshare).
+//!
+//! None of these implementations append fractus' checksum, so corruption is
+//! not detected.
+use crate::gf256::GF256;
+use crate::share::Share;
+
+/// Reads a share file written by libgfshare's `gfsplit`.
+///
+/// Returns `None` if the file name does not end in a three-digit x-coordinate
+/// between 001 and 255, or the file is empty.
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::compat;
+///
+/// let share = compat::from_gfsplit("backup.tar.042", &[0xde, 0xad]).unwrap();
+/// assert_eq!(share.x().0, 42);
+/// assert!(compat::from_gfsplit("backup.tar", &[0xde, 0xad]).is_none());
+/// ```
+pub fn from_gfsplit(file_name: &str, contents: &[u8]) -> Option<Share> {
+    let (_, extension) = file_name.rsplit_once('.')?;
+    if extension.len() != 3 || !extension.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let x: u8 = extension.parse().ok()?;
+    if x == 0 || contents.is_empty() {
+        return None;
+    }
+    Some(Share::new(
+        GF256(x),
+        contents.iter().copied().map(GF256).collect(),
+    ))
+}
+
+/// Converts share `index` of a bc-shamir split, moving the secret from
+/// x = 255 to x = 0.
+///
+/// Returns `None` for indices 254 and 255, which bc-shamir reserves for its
+/// digest and the secret, or for an empty share.
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::compat;
+///
+/// let share = compat::from_bc_shamir(0, &[0x11, 0x22]).unwrap();
+/// assert_eq!(share.x().0, 255);
+/// ```
+pub fn from_bc_shamir(index: u8, contents: &[u8]) -> Option<Share> {
+    if index >= 254 || contents.is_empty() {
+        return None;
+    }
+    Some(Share::new(
+        GF256(index ^ 0xff),
+        contents.iter().copied().map(GF256).collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Shamir;
+    use crate::gf256::{Gf2p8Aes, Gf2p8Gfshare};
+    use rand_chacha::ChaCha8Rng;
+    use rand_chacha::rand_core::SeedableRng;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_recover_sharks_shares() {
+        // sharks works over x^8 + x^4 + x^3 + x^2 + 1 with generator 2, the
+        // field of libgfshare
+        let secret = b"made by another implementation";
+        let sharks = sharks::Sharks(3);
+        let mut rng = ChaCha8Rng::from_seed([0x5a; 32]);
+        let shares: Vec<Share> = sharks
+            .dealer_rng(secret, &mut rng)
+            .take(5)
+            .map(|share| Share::from_bytes(&Vec::from(&share)).unwrap())
+            .collect();
+        let shamir = Shamir::new(3).unwrap();
+
+        for subset in [[0, 1, 2], [4, 2, 0], [1, 3, 4]] {
+            let picked: Vec<&Share> = subset.iter().map(|&i| &shares[i]).collect();
+            assert_eq!(
+                shamir.recover_in::<Gf2p8Gfshare, _>(picked).unwrap(),
+                secret
+            );
+        }
+
+        // For x = 1, 2, 3 every Lagrange weight is 1 in any GF(2^8), so check
+        // that the AES field fails on the other shares
+        assert_ne!(
+            shamir.recover_in::<Gf2p8Aes, _>(&shares[2..]).unwrap(),
+            secret
+        );
+    }
+
+    #[test]
+    fn test_recover_shares_in_gfsplit_files() {
+        // Shares of sharks laid out as gfsplit writes them: y bytes in a file
+        // named after the x-coordinate
+        let secret = b"gfsplit layout";
+        let mut rng = ChaCha8Rng::from_seed([0xa5; 32]);
+        let files: Vec<(String, Vec<u8>)> = sharks::Sharks(2)
+            .dealer_rng(secret, &mut rng)
+            .take(3)
+            .map(|share| {
+                let bytes = Vec::from(&share);
+                (format!("secret.txt.{:03}", bytes[0]), bytes[1..].to_vec())
+            })
+            .collect();
+
+        let shares: Vec<Share> = files
+            .iter()
+            .map(|(name, contents)| from_gfsplit(name, contents).unwrap())
+            .collect();
+        let shamir = Shamir::new(2).unwrap();
+        assert_eq!(
+            shamir.recover_in::<Gf2p8Gfshare, _>(&shares[1..]).unwrap(),
+            secret
+        );
+    }
+
+    #[test]
+    fn test_from_gfsplit_rejects_other_names() {
+        for name in [
+            "secret.txt",
+            "secret.000",
+            "secret.256",
+            "secret.42",
+            "secret.0x1",
+        ] {
+            assert!(from_gfsplit(name, &[1]).is_none(), "{}", name);
+        }
+        assert!(from_gfsplit("secret.001", &[]).is_none());
+    }
+
+    #[test]
+    fn test_recover_bc_shamir_vectors() {
+        // The published vectors of bc-shamir's test suite (test_split_secret_3_5
+        // and test_split_secret_2_7), recovered from the same share indices
+        let shamir = Shamir::new(3).unwrap();
+        let shares = [
+            (1, "d43099fe444807c46921a4f33a2a798b"),
+            (2, "d9ad4e3bec2e1a7485698823abf05d36"),
+            (4, "1aa7fe3199bc5092ef3816b074cabdf2"),
+        ]
+        .map(|(index, hex)| from_bc_shamir(index, &from_hex(hex)).unwrap());
+        assert_eq!(
+            shamir.recover_in::<Gf2p8Aes, _>(&shares).unwrap(),
+            from_hex("0ff784df000c4380a5ed683f7e6e3dcf")
+        );
+        assert_ne!(
+            shamir.recover_in::<Gf2p8Gfshare, _>(&shares).unwrap(),
+            from_hex("0ff784df000c4380a5ed683f7e6e3dcf")
+        );
+
+        let shamir = Shamir::new(2).unwrap();
+        let shares = [
+            (
+                3,
+                "a2fb5414d4d96ee58a109b3ca9a84be0259d2c0f9ac92bdd3199e0eed3f1dd3e",
+            ),
+            (
+                4,
+                "2b851d188b8f5b3653659cc0f7fa45102dadf04b708767385cd803862fcb3c3f",
+            ),
+        ]
+        .map(|(index, hex)| from_bc_shamir(index, &from_hex(hex)).unwrap());
+        assert_eq!(
+            shamir.recover_in::<Gf2p8Aes, _>(&shares).unwrap(),
+            from_hex("204188bfa6b440a1bdfd6753ff55a8241e07af5c5be943db917e3efabc184b1a")
+        );
+    }
+
+    #[test]
+    fn test_from_bc_shamir_rejects_reserved_indices() {
+        assert!(from_bc_shamir(254, &[1]).is_none());
+        assert!(from_bc_shamir(255, &[1]).is_none());
+        assert!(from_bc_shamir(0, &[]).is_none());
+    }
+}
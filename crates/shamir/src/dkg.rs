@@ -0,0 +1,359 @@
+//! Dealer-free generation of a shared random secret.
+//!
+//! Each of `n` participants picks a random contribution, splits it with
+//! [`Shamir`] and sends one sub-share to every participant ([`Dkg::deal`]).
+//! Every participant then adds up the `n` sub-shares it received
+//! ([`Participant::finalize`]). Shamir shares are additively homomorphic, so
+//! the sums are shares of the sum of all contributions: a random secret that
+//! no participant has ever seen, recoverable by any `threshold` of them with
+//! [`Shamir::recover`].
+//!
+//! Sub-shares reveal a participant's contribution to anyone who collects
+//! `threshold` of them, so they must travel over confidential channels. The
+//! protocol assumes participants follow it; it does not detect a dealer that
+//! sends inconsistent sub-shares.
+//!
+//! # Examples
+//! ```
+//! use fractus_shamir::Shamir;
+//! use fractus_shamir::dkg::Dkg;
+//!
+//! let dkg = Dkg::new(2, 3).unwrap();
+//! let mut participants: Vec<_> = (1..=3).map(|id| dkg.participant(id).unwrap()).collect();
+//!
+//! for dealer in 1..=3 {
+//!     let deals = dkg.deal(dealer, 32, &mut rand::thread_rng()).unwrap();
+//!     for deal in deals {
+//!         participants[deal.to as usize - 1].receive(deal).unwrap();
+//!     }
+//! }
+//!
+//! let shares: Vec<_> = participants.into_iter().map(|p| p.finalize().unwrap()).collect();
+//! let secret = Shamir::new(2).unwrap().recover(&shares[1..]).unwrap();
+//! assert_eq!(secret.len(), 32);
+//! ```
+use hashbrown::HashSet;
+use rand::{CryptoRng, RngCore};
+
+use super::{CHECKSUM_LEN, Result, Shamir, ShamirError, Share};
+
+/// Parameters of a joint secret generation: how many participants take part
+/// and how many of them are needed to recover the secret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dkg {
+    threshold: u8,
+    parties: u8,
+}
+
+/// A sub-share sent from one participant to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deal {
+    /// Id of the participant that dealt the sub-share
+    pub from: u8,
+    /// Id of the participant the sub-share is for; also its x-coordinate
+    pub to: u8,
+    /// The sub-share of the dealer's contribution
+    pub share: Share,
+}
+
+impl Dkg {
+    /// Creates the parameters for `parties` participants with ids `1..=parties`,
+    /// any `threshold` of which can recover the joint secret.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidThreshold` if threshold is 0 or exceeds the
+    /// number of participants.
+    pub fn new(threshold: u8, parties: u8) -> Result<Self> {
+        if threshold == 0 || threshold > parties {
+            return Err(ShamirError::InvalidThreshold);
+        }
+        Ok(Self { threshold, parties })
+    }
+
+    /// Returns the number of participants needed to recover the joint secret.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns the number of participants.
+    pub fn parties(&self) -> u8 {
+        self.parties
+    }
+
+    /// Draws participant `from`'s random contribution of `secret_len` bytes and
+    /// splits it into one deal per participant, including `from` itself.
+    ///
+    /// The contribution is not returned and should not be kept.
+    ///
+    /// # Errors
+    /// * `ShamirError::InvalidParticipant` - `from` is not a participant id
+    /// * `ShamirError::EmptyInput` - `secret_len` is zero
+    pub fn deal<R: RngCore + CryptoRng>(
+        &self,
+        from: u8,
+        secret_len: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Deal>> {
+        self.check_id(from)?;
+        if secret_len == 0 {
+            return Err(ShamirError::EmptyInput);
+        }
+
+        let mut contribution = vec![0u8; secret_len];
+        rng.fill_bytes(&mut contribution);
+        let shares = Shamir::new(self.threshold)?.split_with_rng(&contribution, rng);
+        contribution.fill(0);
+
+        Ok(shares?
+            .take(self.parties as usize)
+            .map(|share| Deal {
+                from,
+                to: share.x.0,
+                share,
+            })
+            .collect())
+    }
+
+    /// Creates the state that participant `id` uses to collect its deals.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidParticipant` if `id` is not a participant id.
+    pub fn participant(&self, id: u8) -> Result<Participant> {
+        self.check_id(id)?;
+        Ok(Participant {
+            dkg: *self,
+            id,
+            sum: None,
+            received: HashSet::new(),
+        })
+    }
+
+    fn check_id(&self, id: u8) -> Result<()> {
+        if id == 0 || id > self.parties {
+            return Err(ShamirError::InvalidParticipant(id));
+        }
+        Ok(())
+    }
+}
+
+/// One participant's view of a joint secret generation.
+///
+/// Only the running sum of the received sub-shares is kept, never the
+/// individual sub-shares.
+#[derive(Debug, Clone)]
+pub struct Participant {
+    dkg: Dkg,
+    id: u8,
+    sum: Option<Share>,
+    received: HashSet<u8>,
+}
+
+impl Participant {
+    /// Returns this participant's id, which is also the x-coordinate of its
+    /// final share.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Adds a deal addressed to this participant.
+    ///
+    /// # Errors
+    /// * `ShamirError::InvalidParticipant` - The deal is addressed to someone
+    ///   else or comes from an unknown dealer
+    /// * `ShamirError::InvalidXCoordinate` - The sub-share's x-coordinate is not
+    ///   this participant's id
+    /// * `ShamirError::DuplicateDeal` - A deal from the same dealer was already added
+    /// * `ShamirError::InconsistentShareLength` - The sub-share's length differs
+    ///   from earlier ones
+    pub fn receive(&mut self, deal: Deal) -> Result<()> {
+        if deal.to != self.id {
+            return Err(ShamirError::InvalidParticipant(deal.to));
+        }
+        self.dkg.check_id(deal.from)?;
+        if deal.share.x.0 != self.id {
+            return Err(ShamirError::InvalidXCoordinate);
+        }
+        if self.received.contains(&deal.from) {
+            return Err(ShamirError::DuplicateDeal(deal.from));
+        }
+        if deal.share.y.len() <= CHECKSUM_LEN {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+
+        self.sum = match self.sum.take() {
+            None => Some(deal.share),
+            Some(sum) => Some(sum.checked_add(&deal.share)?),
+        };
+        self.received.insert(deal.from);
+
+        Ok(())
+    }
+
+    /// Returns the ids of the dealers whose deal has not been received yet.
+    pub fn missing(&self) -> Vec<u8> {
+        (1..=self.dkg.parties)
+            .filter(|from| !self.received.contains(from))
+            .collect()
+    }
+
+    /// Returns true once a deal from every participant has been received.
+    pub fn is_complete(&self) -> bool {
+        self.received.len() == self.dkg.parties as usize
+    }
+
+    /// Combines the received deals into this participant's share of the joint
+    /// secret.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InsufficientShares` unless a deal from every
+    /// participant was received.
+    pub fn finalize(self) -> Result<Share> {
+        if !self.is_complete() {
+            return Err(ShamirError::InsufficientShares {
+                required: self.dkg.parties,
+                provided: self.received.len(),
+            });
+        }
+
+        // Share addition keeps the embedded checksum valid
+        Ok(self.sum.expect("a complete participant received deals"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf256::GF256;
+    use rand_chacha::rand_core::SeedableRng;
+
+    fn run(threshold: u8, parties: u8, secret_len: usize, seed: u8) -> Vec<Share> {
+        let dkg = Dkg::new(threshold, parties).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([seed; 32]);
+        let mut participants: Vec<Participant> = (1..=parties)
+            .map(|id| dkg.participant(id).unwrap())
+            .collect();
+
+        for dealer in 1..=parties {
+            for deal in dkg.deal(dealer, secret_len, &mut rng).unwrap() {
+                participants[deal.to as usize - 1].receive(deal).unwrap();
+            }
+        }
+
+        participants
+            .into_iter()
+            .map(|participant| participant.finalize().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_joint_secret_is_recoverable() {
+        // Includes a single participant and even and odd party counts
+        for (threshold, parties) in [(1, 1), (2, 2), (2, 3), (3, 4), (3, 5), (5, 8)] {
+            let shares = run(threshold, parties, 32, parties);
+            let shamir = Shamir::new(threshold).unwrap();
+
+            let secret = shamir.recover(&shares[..threshold as usize]).unwrap();
+            assert_eq!(secret.len(), 32);
+            let tail = shares.len() - threshold as usize;
+            assert_eq!(shamir.recover(&shares[tail..]).unwrap(), secret);
+
+            let (_, report) = shamir.recover_verified(&shares).unwrap();
+            assert!(report.is_consistent());
+        }
+    }
+
+    #[test]
+    fn test_joint_secret_is_the_sum_of_contributions() {
+        let dkg = Dkg::new(2, 3).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([38; 32]);
+        let shamir = Shamir::new(2).unwrap();
+
+        let mut participants: Vec<Participant> =
+            (1..=3).map(|id| dkg.participant(id).unwrap()).collect();
+        let mut expected = vec![0u8; 16];
+        for dealer in 1..=3 {
+            let deals = dkg.deal(dealer, 16, &mut rng).unwrap();
+            let shares: Vec<Share> = deals.iter().map(|deal| deal.share.clone()).collect();
+            let contribution = shamir.recover(&shares[..2]).unwrap();
+            for (sum, byte) in expected.iter_mut().zip(contribution) {
+                *sum ^= byte;
+            }
+            for deal in deals {
+                participants[deal.to as usize - 1].receive(deal).unwrap();
+            }
+        }
+
+        let shares: Vec<Share> = participants
+            .into_iter()
+            .map(|participant| participant.finalize().unwrap())
+            .collect();
+        assert_eq!(shamir.recover(&shares[1..]).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parameter_validation() {
+        assert_eq!(Dkg::new(0, 3), Err(ShamirError::InvalidThreshold));
+        assert_eq!(Dkg::new(4, 3), Err(ShamirError::InvalidThreshold));
+
+        let dkg = Dkg::new(2, 3).unwrap();
+        assert_eq!(
+            dkg.participant(0).err(),
+            Some(ShamirError::InvalidParticipant(0))
+        );
+        assert_eq!(
+            dkg.participant(4).err(),
+            Some(ShamirError::InvalidParticipant(4))
+        );
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([39; 32]);
+        assert_eq!(
+            dkg.deal(4, 16, &mut rng),
+            Err(ShamirError::InvalidParticipant(4))
+        );
+        assert_eq!(dkg.deal(1, 0, &mut rng), Err(ShamirError::EmptyInput));
+    }
+
+    #[test]
+    fn test_receive_validation() {
+        let dkg = Dkg::new(2, 3).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([40; 32]);
+        let deals = dkg.deal(1, 16, &mut rng).unwrap();
+        let mut participant = dkg.participant(2).unwrap();
+
+        assert_eq!(
+            participant.receive(deals[0].clone()),
+            Err(ShamirError::InvalidParticipant(1))
+        );
+
+        participant.receive(deals[1].clone()).unwrap();
+        assert_eq!(
+            participant.receive(deals[1].clone()),
+            Err(ShamirError::DuplicateDeal(1))
+        );
+
+        let mut forged = deals[1].clone();
+        forged.from = 3;
+        forged.share.x = GF256(1);
+        assert_eq!(
+            participant.receive(forged.clone()),
+            Err(ShamirError::InvalidXCoordinate)
+        );
+
+        forged.share.x = GF256(2);
+        forged.share.y.pop();
+        assert_eq!(
+            participant.receive(forged),
+            Err(ShamirError::InconsistentShareLength)
+        );
+
+        assert_eq!(participant.missing(), vec![2, 3]);
+        assert!(!participant.is_complete());
+        assert_eq!(
+            participant.finalize(),
+            Err(ShamirError::InsufficientShares {
+                required: 3,
+                provided: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Finite field abstraction.
+//!
+//! Secret sharing itself runs over [`GF256`], but the algebra in [`crate::poly`]
+//! works over any field. This trait captures the operations it needs.
+use core::fmt::Debug;
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Sub};
+
+use super::gf256::GF256;
+
+/// An element of a finite field.
+///
+/// Division by zero and inverting zero are allowed to panic, as they do for
+/// [`GF256`].
+pub trait Field:
+    Copy
+    + Debug
+    + Eq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Sum
+    + Product
+{
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Computes the multiplicative inverse of this element.
+    ///
+    /// # Panics
+    /// May panic if called on zero.
+    fn inverse(self) -> Self;
+
+    /// Returns true if this is the zero element.
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+}
+
+impl Field for GF256 {
+    const ZERO: Self = GF256::ZERO;
+    const ONE: Self = GF256::ONE;
+
+    #[inline]
+    fn inverse(self) -> Self {
+        GF256::inverse(self)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        GF256::is_zero(*self)
+    }
+}
@@ -0,0 +1,214 @@
+//! GF(2^8) with a caller-chosen reducing polynomial.
+//!
+//! [`GF256`](super::GF256) is fixed to the AES field, which is what fractus
+//! uses for splitting. Other Shamir implementations picked other fields, and
+//! their shares only recover correctly with the same arithmetic; [`Gf2p8`]
+//! provides it for any field, with tables generated at compile time.
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Sub};
+
+use super::tables::Tables;
+use crate::field::Field;
+
+/// An element of GF(2^8) defined by the reducing polynomial `POLYNOMIAL`
+/// (including its x^8 term) and the multiplicative generator `GENERATOR`.
+///
+/// Choosing a polynomial that is not irreducible, or a generator that does not
+/// generate the whole multiplicative group, fails to compile once the type is
+/// used for multiplication.
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::gf256::{Gf2p8, Gf2p8Gfshare};
+///
+/// let a = Gf2p8Gfshare::new(0x80);
+/// assert_eq!(a * Gf2p8::new(2), Gf2p8::new(0x1d));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Gf2p8<const POLYNOMIAL: u16, const GENERATOR: u8>(pub u8);
+
+/// The AES field, x^8 + x^4 + x^3 + x + 1 with generator 3, which is the field
+/// [`GF256`](super::GF256) implements.
+pub type Gf2p8Aes = Gf2p8<0x11b, 3>;
+
+/// The field x^8 + x^4 + x^3 + x^2 + 1 with generator 2, whose reducing
+/// polynomial is the one libgfshare (`gfsplit`/`gfcombine`), sharks and blahaj
+/// use. [`compat`](crate::compat) reads their share layouts; it is tested on
+/// shares made by sharks, not on files written by the `gfsplit` binary.
+pub type Gf2p8Gfshare = Gf2p8<0x11d, 2>;
+
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> Gf2p8<POLYNOMIAL, GENERATOR> {
+    const TABLES: Tables = Tables::new(POLYNOMIAL, GENERATOR);
+
+    /// The zero element.
+    pub const ZERO: Self = Self(0);
+
+    /// The one element.
+    pub const ONE: Self = Self(1);
+
+    /// Creates an element from a byte value.
+    #[inline]
+    pub const fn new(value: u8) -> Self {
+        Self(value)
+    }
+
+    /// Returns the underlying byte value.
+    #[inline]
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+
+    /// Computes the multiplicative inverse of this element.
+    ///
+    /// # Panics
+    /// Panics if called on the zero element (which has no inverse).
+    #[inline]
+    pub fn inverse(self) -> Self {
+        assert_ne!(self.0, 0, "Zero element has no multiplicative inverse");
+        let log_val = Self::TABLES.log[self.0 as usize] as usize;
+        Self(Self::TABLES.exp[255 - log_val])
+    }
+}
+
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> From<u8> for Gf2p8<POLYNOMIAL, GENERATOR> {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> From<Gf2p8<POLYNOMIAL, GENERATOR>> for u8 {
+    #[inline]
+    fn from(element: Gf2p8<POLYNOMIAL, GENERATOR>) -> u8 {
+        element.0
+    }
+}
+
+// Addition in GF(2^8) is XOR
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> Add for Gf2p8<POLYNOMIAL, GENERATOR> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+}
+
+// Subtraction in GF(2^n) equals addition
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> Sub for Gf2p8<POLYNOMIAL, GENERATOR> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+}
+
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> Mul for Gf2p8<POLYNOMIAL, GENERATOR> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        if self.0 == 0 || other.0 == 0 {
+            Self::ZERO
+        } else {
+            let x = Self::TABLES.log[self.0 as usize] as usize;
+            let y = Self::TABLES.log[other.0 as usize] as usize;
+            Self(Self::TABLES.exp[x + y])
+        }
+    }
+}
+
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> Div for Gf2p8<POLYNOMIAL, GENERATOR> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        assert_ne!(other.0, 0, "Division by zero in GF(2^8)");
+        self * other.inverse()
+    }
+}
+
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> Sum for Gf2p8<POLYNOMIAL, GENERATOR> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> Product for Gf2p8<POLYNOMIAL, GENERATOR> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<const POLYNOMIAL: u16, const GENERATOR: u8> Field for Gf2p8<POLYNOMIAL, GENERATOR> {
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1);
+
+    #[inline]
+    fn inverse(self) -> Self {
+        Gf2p8::inverse(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf256::GF256;
+    use crate::gf256::tables::mul_reduce;
+
+    #[test]
+    fn test_aes_field_matches_gf256() {
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                assert_eq!(
+                    (Gf2p8Aes::new(a) * Gf2p8Aes::new(b)).0,
+                    (GF256(a) * GF256(b)).0
+                );
+            }
+            if a != 0 {
+                assert_eq!(Gf2p8Aes::new(a).inverse().0, GF256(a).inverse().0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gfshare_field() {
+        for a in 1..=255u8 {
+            let x = Gf2p8Gfshare::new(a);
+            assert_eq!(x * x.inverse(), Gf2p8Gfshare::ONE);
+            for b in [1u8, 2, 0x1d, 0x80, 0xff] {
+                assert_eq!((x * Gf2p8::new(b)).0, mul_reduce(a, b, 0x11d));
+                assert_eq!(x * Gf2p8::new(b) / Gf2p8::new(b), x);
+            }
+        }
+
+        // The two fields really differ
+        assert_ne!(
+            (Gf2p8Gfshare::new(0x80) * Gf2p8::new(2)).0,
+            (GF256(0x80) * GF256(2)).0
+        );
+    }
+
+    #[test]
+    fn test_polynomials_over_custom_field() {
+        use crate::poly::Polynomial;
+
+        let f = Polynomial::new(vec![Gf2p8Gfshare::new(7), Gf2p8::new(11), Gf2p8::new(13)]);
+        let points: Vec<_> = [1u8, 2, 3]
+            .map(Gf2p8::new)
+            .into_iter()
+            .map(|x| (x, f.evaluate(x)))
+            .collect();
+        assert_eq!(Polynomial::interpolate(&points).unwrap(), f);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_division_by_zero() {
+        let _ = Gf2p8Gfshare::ONE / Gf2p8Gfshare::ZERO;
+    }
+}
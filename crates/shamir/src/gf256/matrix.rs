@@ -0,0 +1,496 @@
+//! Dense matrices and linear algebra over GF(256).
+//!
+//! Matrices are generic over [`Field`] and default to [`GF256`], so every
+//! entry operation goes through the field's own arithmetic.
+use core::ops::{Index, IndexMut, Mul};
+
+use super::GF256;
+use crate::field::Field;
+use crate::{Result, ShamirError};
+
+/// A dense matrix stored in row-major order.
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::gf256::GF256;
+/// use fractus_shamir::gf256::matrix::Matrix;
+///
+/// let xs = [GF256(1), GF256(2), GF256(3)];
+/// let vandermonde = Matrix::vandermonde(&xs, 3);
+/// let inverse = vandermonde.inverse().unwrap();
+/// assert_eq!(&vandermonde * &inverse, Matrix::identity(3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<F = GF256> {
+    rows: usize,
+    cols: usize,
+    data: Vec<F>,
+}
+
+impl<F: Field> Matrix<F> {
+    /// Creates a `rows` x `cols` matrix filled with zeros.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![F::ZERO; rows * cols],
+        }
+    }
+
+    /// Creates the `n` x `n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::zeros(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = F::ONE;
+        }
+        matrix
+    }
+
+    /// Creates a matrix from its rows.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::DimensionMismatch` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<F>>) -> Result<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            return Err(ShamirError::DimensionMismatch);
+        }
+
+        Ok(Self {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Creates the Vandermonde matrix with entry `(i, j) = xs[i]^j`.
+    ///
+    /// Multiplying it by a column of polynomial coefficients (ascending order)
+    /// evaluates the polynomial at every `x`. A square Vandermonde matrix is
+    /// invertible exactly when the `xs` are distinct.
+    pub fn vandermonde(xs: &[F], cols: usize) -> Self {
+        let mut matrix = Self::zeros(xs.len(), cols);
+        for (i, &x) in xs.iter().enumerate() {
+            let mut power = F::ONE;
+            for j in 0..cols {
+                matrix[(i, j)] = power;
+                power = power * x;
+            }
+        }
+        matrix
+    }
+
+    /// Creates the Cauchy matrix with entry `(i, j) = 1 / (xs[i] - ys[j])`.
+    ///
+    /// When all `xs` and `ys` are distinct, every square submatrix of a Cauchy
+    /// matrix is invertible.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::DuplicatePoints` if any element appears twice
+    /// across `xs` and `ys`.
+    pub fn cauchy(xs: &[F], ys: &[F]) -> Result<Self> {
+        let all: Vec<F> = xs.iter().chain(ys).copied().collect();
+        for (i, a) in all.iter().enumerate() {
+            if all[i + 1..].contains(a) {
+                return Err(ShamirError::DuplicatePoints);
+            }
+        }
+
+        let mut matrix = Self::zeros(xs.len(), ys.len());
+        for (i, &x) in xs.iter().enumerate() {
+            for (j, &y) in ys.iter().enumerate() {
+                matrix[(i, j)] = (x - y).inverse();
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns true if the matrix has as many rows as columns.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns row `r` as a slice.
+    ///
+    /// # Panics
+    /// Panics if `r` is out of bounds.
+    pub fn row(&self, r: usize) -> &[F] {
+        &self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    /// Returns the transpose.
+    pub fn transpose(&self) -> Self {
+        let mut transposed = Self::zeros(self.cols, self.rows);
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                transposed[(c, r)] = self[(r, c)];
+            }
+        }
+        transposed
+    }
+
+    /// Multiplies the matrix by a column vector.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::DimensionMismatch` if `v` does not have one entry
+    /// per column.
+    pub fn mul_vector(&self, v: &[F]) -> Result<Vec<F>> {
+        if v.len() != self.cols {
+            return Err(ShamirError::DimensionMismatch);
+        }
+
+        Ok((0..self.rows)
+            .map(|r| self.row(r).iter().zip(v).map(|(&a, &b)| a * b).sum())
+            .collect())
+    }
+
+    /// Brings the matrix into reduced row echelon form with Gaussian
+    /// elimination and returns its rank.
+    ///
+    /// Every pivot is one and is the only non-zero entry in its column.
+    pub fn gaussian_elimination(&mut self) -> usize {
+        self.eliminate(self.cols)
+    }
+
+    /// Returns the reduced row echelon form, leaving `self` untouched.
+    pub fn reduced_row_echelon(&self) -> Self {
+        let mut reduced = self.clone();
+        reduced.gaussian_elimination();
+        reduced
+    }
+
+    /// Returns the rank: the number of linearly independent rows.
+    pub fn rank(&self) -> usize {
+        self.clone().gaussian_elimination()
+    }
+
+    /// Computes the inverse of a square matrix.
+    ///
+    /// # Errors
+    /// * `ShamirError::DimensionMismatch` - The matrix is not square
+    /// * `ShamirError::SingularMatrix` - The matrix has no inverse
+    pub fn inverse(&self) -> Result<Self> {
+        if !self.is_square() {
+            return Err(ShamirError::DimensionMismatch);
+        }
+
+        let n = self.rows;
+        let mut augmented = self.augment(&Self::identity(n));
+        if augmented.eliminate(n) < n {
+            return Err(ShamirError::SingularMatrix);
+        }
+
+        let mut inverse = Self::zeros(n, n);
+        for r in 0..n {
+            inverse.data[r * n..(r + 1) * n].copy_from_slice(&augmented.row(r)[n..]);
+        }
+        Ok(inverse)
+    }
+
+    /// Solves `self * x = b` for `x`.
+    ///
+    /// The matrix may have more rows than columns, in which case the extra
+    /// equations must be consistent with the others.
+    ///
+    /// # Errors
+    /// * `ShamirError::DimensionMismatch` - `b` does not have one entry per row
+    /// * `ShamirError::SingularMatrix` - The columns are linearly dependent, so
+    ///   a solution would not be unique
+    /// * `ShamirError::InconsistentSystem` - No `x` satisfies every equation
+    pub fn solve(&self, b: &[F]) -> Result<Vec<F>> {
+        if b.len() != self.rows {
+            return Err(ShamirError::DimensionMismatch);
+        }
+
+        let column = Self {
+            rows: self.rows,
+            cols: 1,
+            data: b.to_vec(),
+        };
+        let mut augmented = self.augment(&column);
+        let rank = augmented.eliminate(self.cols);
+
+        // A non-zero right-hand side left in an all-zero row means 0 = c
+        if (rank..self.rows).any(|r| !augmented[(r, self.cols)].is_zero()) {
+            return Err(ShamirError::InconsistentSystem);
+        }
+        if rank < self.cols {
+            return Err(ShamirError::SingularMatrix);
+        }
+
+        Ok((0..self.cols).map(|r| augmented[(r, self.cols)]).collect())
+    }
+
+    /// Places `other` to the right of `self`.
+    fn augment(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.rows, other.rows);
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        for r in 0..self.rows {
+            data.extend_from_slice(self.row(r));
+            data.extend_from_slice(other.row(r));
+        }
+        Self {
+            rows: self.rows,
+            cols: self.cols + other.cols,
+            data,
+        }
+    }
+
+    /// Gauss-Jordan elimination choosing pivots only among the first
+    /// `pivot_cols` columns; returns the number of pivots found.
+    fn eliminate(&mut self, pivot_cols: usize) -> usize {
+        let mut rank = 0;
+        for col in 0..pivot_cols {
+            let Some(pivot) = (rank..self.rows).find(|&r| !self[(r, col)].is_zero()) else {
+                continue;
+            };
+            self.swap_rows(rank, pivot);
+
+            let scale = self[(rank, col)].inverse();
+            for c in col..self.cols {
+                self[(rank, c)] = self[(rank, c)] * scale;
+            }
+
+            for r in 0..self.rows {
+                let factor = self[(r, col)];
+                if r == rank || factor.is_zero() {
+                    continue;
+                }
+                for c in col..self.cols {
+                    self[(r, c)] = self[(r, c)] - factor * self[(rank, c)];
+                }
+            }
+
+            rank += 1;
+            if rank == self.rows {
+                break;
+            }
+        }
+        rank
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for c in 0..self.cols {
+                self.data.swap(a * self.cols + c, b * self.cols + c);
+            }
+        }
+    }
+}
+
+impl<F> Index<(usize, usize)> for Matrix<F> {
+    type Output = F;
+
+    fn index(&self, (r, c): (usize, usize)) -> &F {
+        assert!(r < self.rows && c < self.cols, "matrix index out of bounds");
+        &self.data[r * self.cols + c]
+    }
+}
+
+impl<F> IndexMut<(usize, usize)> for Matrix<F> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut F {
+        assert!(r < self.rows && c < self.cols, "matrix index out of bounds");
+        &mut self.data[r * self.cols + c]
+    }
+}
+
+impl<F: Field> Mul for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    /// # Panics
+    /// Panics if the left operand's column count differs from the right
+    /// operand's row count.
+    fn mul(self, rhs: Self) -> Matrix<F> {
+        assert_eq!(self.cols, rhs.rows, "matrix dimensions do not match");
+
+        let mut product = Matrix::zeros(self.rows, rhs.cols);
+        for r in 0..self.rows {
+            for c in 0..rhs.cols {
+                product[(r, c)] = (0..self.cols).map(|k| self[(r, k)] * rhs[(k, c)]).sum();
+            }
+        }
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poly::Polynomial;
+    use rand::Rng;
+    use rand_chacha::rand_core::SeedableRng;
+
+    fn matrix(rows: &[&[u8]]) -> Matrix {
+        Matrix::from_rows(
+            rows.iter()
+                .map(|row| row.iter().copied().map(GF256).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn random_matrix<R: Rng>(rows: usize, cols: usize, rng: &mut R) -> Matrix {
+        Matrix::from_rows(
+            (0..rows)
+                .map(|_| (0..cols).map(|_| GF256(rng.r#gen())).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_construction() {
+        let m = matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m[(1, 2)], GF256(6));
+        assert_eq!(m.row(0), &[GF256(1), GF256(2), GF256(3)]);
+        assert_eq!(m.transpose()[(2, 1)], GF256(6));
+        assert_eq!(
+            Matrix::from_rows(vec![vec![GF256(1)], vec![]]),
+            Err(ShamirError::DimensionMismatch)
+        );
+    }
+
+    #[test]
+    fn test_vandermonde_evaluates_polynomials() {
+        let xs = [GF256(1), GF256(7), GF256(200)];
+        let polynomial = Polynomial::new(vec![GF256(9), GF256(8), GF256(7), GF256(6)]);
+        let values = Matrix::vandermonde(&xs, 4)
+            .mul_vector(polynomial.coefficients())
+            .unwrap();
+        assert_eq!(values, polynomial.evaluate_many(&xs));
+    }
+
+    #[test]
+    fn test_vandermonde_solve_interpolates() {
+        let xs = [GF256(3), GF256(5), GF256(11), GF256(254)];
+        let polynomial = Polynomial::new(vec![GF256(42), GF256(1), GF256(0), GF256(99)]);
+        let ys = polynomial.evaluate_many(&xs);
+
+        let coefficients = Matrix::vandermonde(&xs, 4).solve(&ys).unwrap();
+        assert_eq!(coefficients, polynomial.coefficients());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([34; 32]);
+        let mut inverted = 0;
+        for n in 1..10 {
+            for _ in 0..10 {
+                let m = random_matrix(n, n, &mut rng);
+                match m.inverse() {
+                    Ok(inverse) => {
+                        assert_eq!(&m * &inverse, Matrix::identity(n));
+                        assert_eq!(&inverse * &m, Matrix::identity(n));
+                        assert_eq!(m.rank(), n);
+                        inverted += 1;
+                    }
+                    Err(error) => {
+                        assert_eq!(error, ShamirError::SingularMatrix);
+                        assert!(m.rank() < n);
+                    }
+                }
+            }
+        }
+        assert!(inverted > 80);
+    }
+
+    #[test]
+    fn test_cauchy_submatrices_are_invertible() {
+        let xs: Vec<GF256> = (1..=6).map(GF256).collect();
+        let ys: Vec<GF256> = (100..=105).map(GF256).collect();
+        let cauchy = Matrix::cauchy(&xs, &ys).unwrap();
+        assert!(cauchy.inverse().is_ok());
+
+        // Any choice of rows and columns yields an invertible submatrix
+        let picked = [(0, 5), (2, 1), (5, 3)];
+        let sub = Matrix::from_rows(
+            picked
+                .iter()
+                .map(|&(r, _)| picked.iter().map(|&(_, c)| cauchy[(r, c)]).collect())
+                .collect(),
+        )
+        .unwrap();
+        assert!(sub.inverse().is_ok());
+
+        assert_eq!(
+            Matrix::cauchy(&[GF256(1), GF256(2)], &[GF256(2)]),
+            Err(ShamirError::DuplicatePoints)
+        );
+    }
+
+    #[test]
+    fn test_singular_matrices() {
+        // Zero matrix
+        assert_eq!(
+            Matrix::<GF256>::zeros(3, 3).inverse(),
+            Err(ShamirError::SingularMatrix)
+        );
+        assert_eq!(Matrix::<GF256>::zeros(3, 3).rank(), 0);
+
+        // Repeated row
+        let repeated = matrix(&[&[1, 2, 3], &[4, 5, 6], &[1, 2, 3]]);
+        assert_eq!(repeated.rank(), 2);
+        assert_eq!(repeated.inverse(), Err(ShamirError::SingularMatrix));
+
+        // Third row is the sum (XOR) of the first two
+        let dependent = matrix(&[&[1, 2, 3], &[4, 5, 6], &[5, 7, 5]]);
+        assert_eq!(dependent.rank(), 2);
+        assert_eq!(
+            dependent.solve(&[GF256(1), GF256(2), GF256(3)]),
+            Err(ShamirError::SingularMatrix)
+        );
+
+        // Vandermonde matrix with a repeated x
+        let xs = [GF256(4), GF256(9), GF256(4)];
+        assert_eq!(
+            Matrix::vandermonde(&xs, 3).inverse(),
+            Err(ShamirError::SingularMatrix)
+        );
+    }
+
+    #[test]
+    fn test_solve_overdetermined() {
+        let xs: Vec<GF256> = (1..=5).map(GF256).collect();
+        let polynomial = Polynomial::new(vec![GF256(17), GF256(34)]);
+        let mut ys = polynomial.evaluate_many(&xs);
+
+        let vandermonde = Matrix::vandermonde(&xs, 2);
+        assert_eq!(vandermonde.solve(&ys).unwrap(), polynomial.coefficients());
+
+        ys[4] = ys[4] + GF256(1);
+        assert_eq!(vandermonde.solve(&ys), Err(ShamirError::InconsistentSystem));
+    }
+
+    #[test]
+    fn test_dimension_errors() {
+        let m = matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(m.inverse(), Err(ShamirError::DimensionMismatch));
+        assert_eq!(m.solve(&[GF256(1)]), Err(ShamirError::DimensionMismatch));
+        assert_eq!(
+            m.mul_vector(&[GF256(1)]),
+            Err(ShamirError::DimensionMismatch)
+        );
+        assert_eq!(m.rank(), 2);
+    }
+
+    #[test]
+    fn test_reduced_row_echelon() {
+        let m = matrix(&[&[0, 2, 4], &[3, 6, 9]]);
+        let reduced = m.reduced_row_echelon();
+        assert_eq!(reduced[(0, 0)], GF256::ONE);
+        assert_eq!(reduced[(1, 0)], GF256::ZERO);
+        assert_eq!(reduced[(1, 1)], GF256::ONE);
+        assert_eq!(reduced[(0, 1)], GF256::ZERO);
+    }
+}
@@ -0,0 +1,281 @@
+//! Bulk GF(256) operations over byte slices.
+//!
+//! The split and recover inner loops both reduce to "multiply a long run of
+//! bytes by one constant and add (XOR) the result into an accumulator". Doing
+//! that one log/exp lookup at a time leaves most of the CPU idle, so this module
+//! uses the split-nibble technique from ISA-L and gf-complete instead: for a
+//! fixed constant `c`, every product `c * b` equals `c * (b & 0x0f) ^ c * (b & 0xf0)`,
+//! and both halves come from 16-entry tables. Those tables fit in a single vector
+//! register, so a byte shuffle (PSHUFB on x86, TBL on ARM) multiplies 16 or 32
+//! bytes per instruction.
+//!
+//! The widest available implementation is picked at runtime; every path
+//! produces identical results.
+
+use super::GF256;
+
+/// Multiplies `src` by `c` and adds the result into `dst`, element by element.
+///
+/// This computes `dst[i] = dst[i] + c * src[i]` in GF(256) for every `i`, using
+/// AVX2 or SSSE3 on x86-64 when the CPU supports them, NEON on AArch64 and a
+/// portable table-driven loop everywhere else.
+///
+/// # Panics
+/// Panics if `dst` and `src` have different lengths.
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::gf256::{GF256, mul_add_slice};
+///
+/// let src = [1, 2, 3, 4];
+/// let mut dst = [0, 0, 0, 0xff];
+/// mul_add_slice(&mut dst, &src, GF256(2));
+/// assert_eq!(dst, [2, 4, 6, 0xff ^ 8]);
+/// ```
+pub fn mul_add_slice(dst: &mut [u8], src: &[u8], c: GF256) {
+    assert_eq!(dst.len(), src.len(), "Slices must have the same length");
+
+    if c.is_zero() {
+        return;
+    }
+    if c.is_one() {
+        dst.iter_mut().zip(src).for_each(|(d, &s)| *d ^= s);
+        return;
+    }
+
+    let tables = NibbleTables::new(c);
+    let done = simd_mul_add(dst, src, &tables);
+    portable_mul_add(&mut dst[done..], &src[done..], &tables);
+}
+
+/// Products of a constant with every low nibble and every high nibble.
+struct NibbleTables {
+    low: [u8; 16],
+    high: [u8; 16],
+}
+
+impl NibbleTables {
+    fn new(c: GF256) -> Self {
+        let mut low = [0u8; 16];
+        let mut high = [0u8; 16];
+        for nibble in 0..16u8 {
+            low[nibble as usize] = (c * GF256(nibble)).0;
+            high[nibble as usize] = (c * GF256(nibble << 4)).0;
+        }
+        Self { low, high }
+    }
+}
+
+fn portable_mul_add(dst: &mut [u8], src: &[u8], tables: &NibbleTables) {
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d ^= tables.low[(s & 0x0f) as usize] ^ tables.high[(s >> 4) as usize];
+    }
+}
+
+/// Runs the widest available vector kernel and returns how many leading bytes
+/// it processed. The remaining tail is left to the portable loop.
+#[cfg(target_arch = "x86_64")]
+fn simd_mul_add(dst: &mut [u8], src: &[u8], tables: &NibbleTables) -> usize {
+    if std::is_x86_feature_detected!("avx2") {
+        // SAFETY: the CPU supports AVX2, checked just above.
+        unsafe { x86::mul_add_avx2(dst, src, tables) }
+    } else if std::is_x86_feature_detected!("ssse3") {
+        // SAFETY: the CPU supports SSSE3, checked just above.
+        unsafe { x86::mul_add_ssse3(dst, src, tables) }
+    } else {
+        0
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn simd_mul_add(dst: &mut [u8], src: &[u8], tables: &NibbleTables) -> usize {
+    // SAFETY: NEON is part of the AArch64 baseline.
+    unsafe { neon::mul_add_neon(dst, src, tables) }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn simd_mul_add(_dst: &mut [u8], _src: &[u8], _tables: &NibbleTables) -> usize {
+    0
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::NibbleTables;
+    use core::arch::x86_64::*;
+
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn mul_add_ssse3(dst: &mut [u8], src: &[u8], tables: &NibbleTables) -> usize {
+        let len = dst.len() - dst.len() % 16;
+        // SAFETY: every load and store stays within the first `len` bytes of
+        // `src` and `dst`, which have the same length, and uses unaligned access.
+        unsafe {
+            let low = _mm_loadu_si128(tables.low.as_ptr().cast());
+            let high = _mm_loadu_si128(tables.high.as_ptr().cast());
+            let mask = _mm_set1_epi8(0x0f);
+
+            for offset in (0..len).step_by(16) {
+                let s = _mm_loadu_si128(src.as_ptr().add(offset).cast());
+                let d = _mm_loadu_si128(dst.as_ptr().add(offset).cast());
+                let lo = _mm_shuffle_epi8(low, _mm_and_si128(s, mask));
+                let hi = _mm_shuffle_epi8(high, _mm_and_si128(_mm_srli_epi64(s, 4), mask));
+                let product = _mm_xor_si128(lo, hi);
+                _mm_storeu_si128(
+                    dst.as_mut_ptr().add(offset).cast(),
+                    _mm_xor_si128(d, product),
+                );
+            }
+        }
+        len
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn mul_add_avx2(dst: &mut [u8], src: &[u8], tables: &NibbleTables) -> usize {
+        let len = dst.len() - dst.len() % 32;
+        // SAFETY: every load and store stays within the first `len` bytes of
+        // `src` and `dst`, which have the same length, and uses unaligned access.
+        unsafe {
+            // VPSHUFB shuffles within each 128-bit lane, so both lanes get a copy
+            let low = _mm256_broadcastsi128_si256(_mm_loadu_si128(tables.low.as_ptr().cast()));
+            let high = _mm256_broadcastsi128_si256(_mm_loadu_si128(tables.high.as_ptr().cast()));
+            let mask = _mm256_set1_epi8(0x0f);
+
+            for offset in (0..len).step_by(32) {
+                let s = _mm256_loadu_si256(src.as_ptr().add(offset).cast());
+                let d = _mm256_loadu_si256(dst.as_ptr().add(offset).cast());
+                let lo = _mm256_shuffle_epi8(low, _mm256_and_si256(s, mask));
+                let hi = _mm256_shuffle_epi8(high, _mm256_and_si256(_mm256_srli_epi64(s, 4), mask));
+                let product = _mm256_xor_si256(lo, hi);
+                _mm256_storeu_si256(
+                    dst.as_mut_ptr().add(offset).cast(),
+                    _mm256_xor_si256(d, product),
+                );
+            }
+        }
+        len
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use super::NibbleTables;
+    use core::arch::aarch64::*;
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn mul_add_neon(dst: &mut [u8], src: &[u8], tables: &NibbleTables) -> usize {
+        let len = dst.len() - dst.len() % 16;
+        // SAFETY: every load and store stays within the first `len` bytes of
+        // `src` and `dst`, which have the same length.
+        unsafe {
+            let low = vld1q_u8(tables.low.as_ptr());
+            let high = vld1q_u8(tables.high.as_ptr());
+            let mask = vdupq_n_u8(0x0f);
+
+            for offset in (0..len).step_by(16) {
+                let s = vld1q_u8(src.as_ptr().add(offset));
+                let d = vld1q_u8(dst.as_ptr().add(offset));
+                let lo = vqtbl1q_u8(low, vandq_u8(s, mask));
+                let hi = vqtbl1q_u8(high, vshrq_n_u8::<4>(s));
+                vst1q_u8(dst.as_mut_ptr().add(offset), veorq_u8(d, veorq_u8(lo, hi)));
+            }
+        }
+        len
+    }
+}
+
+/// Views a slice of field elements as their underlying bytes.
+pub(crate) fn as_bytes(elements: &[GF256]) -> &[u8] {
+    // SAFETY: `GF256` is `#[repr(transparent)]` over `u8`, so a slice of it has
+    // the same layout as a byte slice of the same length.
+    unsafe { core::slice::from_raw_parts(elements.as_ptr().cast(), elements.len()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference_mul_add(dst: &mut [u8], src: &[u8], c: GF256) {
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d = (GF256(*d) + c * GF256(s)).0;
+        }
+    }
+
+    fn test_data(len: usize) -> (Vec<u8>, Vec<u8>) {
+        let src = (0..len).map(|i| (i * 37 + 11) as u8).collect();
+        let dst = (0..len).map(|i| (i * 101 + 3) as u8).collect();
+        (src, dst)
+    }
+
+    #[test]
+    fn test_mul_add_slice_all_constants() {
+        let (src, dst) = test_data(256 + 45);
+
+        for c in 0..=255u8 {
+            let mut expected = dst.clone();
+            reference_mul_add(&mut expected, &src, GF256(c));
+
+            let mut actual = dst.clone();
+            mul_add_slice(&mut actual, &src, GF256(c));
+            assert_eq!(actual, expected, "constant {}", c);
+        }
+    }
+
+    #[test]
+    fn test_mul_add_slice_lengths_and_offsets() {
+        let (src, dst) = test_data(200);
+
+        // Cover empty slices, tails shorter than a vector and unaligned starts
+        for offset in 0..4 {
+            for len in 0..(200 - offset) {
+                let range = offset..offset + len;
+                let mut expected = dst[range.clone()].to_vec();
+                reference_mul_add(&mut expected, &src[range.clone()], GF256(0x53));
+
+                let mut actual = dst[range.clone()].to_vec();
+                mul_add_slice(&mut actual, &src[range], GF256(0x53));
+                assert_eq!(actual, expected, "offset {} len {}", offset, len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_portable_matches_field_multiplication() {
+        let (src, dst) = test_data(64);
+        for c in [2u8, 3, 0x8d, 0xff] {
+            let mut expected = dst.clone();
+            reference_mul_add(&mut expected, &src, GF256(c));
+
+            let mut actual = dst.clone();
+            portable_mul_add(&mut actual, &src, &NibbleTables::new(GF256(c)));
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_x86_kernels_match_portable() {
+        let (src, dst) = test_data(1000);
+        let tables = NibbleTables::new(GF256(0xca));
+
+        let mut expected = dst.clone();
+        portable_mul_add(&mut expected, &src, &tables);
+
+        if std::is_x86_feature_detected!("ssse3") {
+            let mut actual = dst.clone();
+            let done = unsafe { x86::mul_add_ssse3(&mut actual, &src, &tables) };
+            portable_mul_add(&mut actual[done..], &src[done..], &tables);
+            assert_eq!(actual, expected);
+        }
+        if std::is_x86_feature_detected!("avx2") {
+            let mut actual = dst.clone();
+            let done = unsafe { x86::mul_add_avx2(&mut actual, &src, &tables) };
+            portable_mul_add(&mut actual[done..], &src[done..], &tables);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_as_bytes() {
+        let elements = [GF256(1), GF256(0), GF256(255)];
+        assert_eq!(as_bytes(&elements), &[1, 0, 255]);
+    }
+}
@@ -0,0 +1,122 @@
+//! Compile-time generation of logarithm and exponential tables.
+
+/// Logarithm and exponential tables for GF(2^8) with a given reducing
+/// polynomial and generator.
+///
+/// `exp[i]` is `generator^i`, stored twice over so that `exp[log[a] + log[b]]`
+/// never needs a modulo. `log[0]` is unused and set to `0xff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Tables {
+    pub(crate) log: [u8; 256],
+    pub(crate) exp: [u8; 255 * 2],
+}
+
+impl Tables {
+    /// Builds the tables for the field defined by `polynomial`, given with its
+    /// x^8 term (e.g. `0x11b` for x^8 + x^4 + x^3 + x + 1).
+    ///
+    /// # Panics
+    /// Panics, at compile time when used in a constant, if `polynomial` does not
+    /// have degree 8 or if `generator` does not generate all 255 non-zero
+    /// elements. The latter also rejects reducible polynomials, which do not
+    /// define a field.
+    pub(crate) const fn new(polynomial: u16, generator: u8) -> Self {
+        assert!(
+            polynomial >> 8 == 1,
+            "reducing polynomial must have degree 8"
+        );
+
+        let mut log = [0u8; 256];
+        let mut exp = [0u8; 255 * 2];
+        let mut seen = [false; 256];
+
+        let mut element = 1u8;
+        let mut i = 0;
+        while i < 255 {
+            assert!(
+                element != 0 && !seen[element as usize],
+                "generator does not generate the multiplicative group"
+            );
+            seen[element as usize] = true;
+
+            exp[i] = element;
+            exp[i + 255] = element;
+            log[element as usize] = i as u8;
+            element = mul_reduce(element, generator, polynomial);
+            i += 1;
+        }
+        log[0] = 0xff;
+
+        Self { log, exp }
+    }
+}
+
+/// Carry-less multiplication of `a` and `b` reduced modulo `polynomial`.
+pub(crate) const fn mul_reduce(mut a: u8, mut b: u8, polynomial: u16) -> u8 {
+    let reduction = (polynomial & 0xff) as u8;
+    let mut product = 0u8;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= reduction;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_agree_with_direct_multiplication() {
+        for (polynomial, generator) in [(0x11b, 3), (0x11d, 2), (0x12d, 2)] {
+            let tables = Tables::new(polynomial, generator);
+            for a in 1..=255u8 {
+                for b in 1..=255u8 {
+                    let via_tables = tables.exp
+                        [tables.log[a as usize] as usize + tables.log[b as usize] as usize];
+                    assert_eq!(via_tables, mul_reduce(a, b, polynomial));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_published_values() {
+        // FIPS-197 section 4.2: {57} * {83} = {c1}, and {53} is the inverse of {ca}
+        assert_eq!(mul_reduce(0x57, 0x83, 0x11b), 0xc1);
+        assert_eq!(mul_reduce(0x53, 0xca, 0x11b), 0x01);
+
+        // ISO/IEC 18004 (QR code) Reed-Solomon field: a^8 = 29, a^25 = 3
+        let qr = Tables::new(0x11d, 2);
+        assert_eq!(qr.exp[8], 29);
+        assert_eq!(qr.exp[25], 3);
+        assert_eq!(qr.log[3], 25);
+    }
+
+    #[test]
+    #[should_panic(expected = "generator does not generate")]
+    fn test_rejects_non_generator() {
+        // 2 has order 51 in the AES field
+        Tables::new(0x11b, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "generator does not generate")]
+    fn test_rejects_reducible_polynomial() {
+        // x^8 + 1 = (x + 1)^8
+        Tables::new(0x101, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "degree 8")]
+    fn test_rejects_wrong_degree() {
+        Tables::new(0x1b, 3);
+    }
+}
@@ -0,0 +1,123 @@
+//! Hedged coefficient randomness.
+//!
+//! Rather than using the caller's RNG output directly, the coefficient stream
+//! is seeded with
+//!
+//! ```text
+//! SHA-256("fractus-shamir/hedged-split/v1" || threshold || len(secret) || r || secret)
+//! ```
+//!
+//! where `threshold` is one byte, `len(secret)` is a big-endian u64 and `r` is
+//! 32 bytes drawn from the RNG. The seed feeds a ChaCha20 stream that takes the
+//! RNG's place. As with RFC 6979's hedged signatures, a good RNG keeps the
+//! coefficients uniformly random, while a broken or predictable one still
+//! yields coefficients that cannot be guessed without knowing the secret.
+use rand::{CryptoRng, RngCore};
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
+use sha2::{Digest, Sha256};
+
+/// Domain separation tag, versioned so the construction can change later.
+const DOMAIN: &[u8] = b"fractus-shamir/hedged-split/v1";
+
+/// Returns an RNG for the coefficients of `secret`, seeded from both `rng` and
+/// the secret itself.
+pub(crate) fn hedged_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret: &[u8],
+    threshold: u8,
+) -> ChaCha20Rng {
+    let mut fresh = [0u8; 32];
+    rng.fill_bytes(&mut fresh);
+
+    let seed = Sha256::new()
+        .chain_update(DOMAIN)
+        .chain_update([threshold])
+        .chain_update((secret.len() as u64).to_be_bytes())
+        .chain_update(fresh)
+        .chain_update(secret)
+        .finalize();
+
+    ChaCha20Rng::from_seed(seed.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An RNG that always returns zeros, as a stand-in for a broken one.
+    struct ZeroRng;
+
+    impl RngCore for ZeroRng {
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(0);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            dest.fill(0);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for ZeroRng {}
+
+    fn first_bytes(mut rng: ChaCha20Rng) -> [u8; 8] {
+        let mut bytes = [0u8; 8];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn test_seed_depends_on_every_input() {
+        let base = first_bytes(hedged_rng(&mut ZeroRng, b"secret", 3));
+
+        assert_eq!(base, first_bytes(hedged_rng(&mut ZeroRng, b"secret", 3)));
+        assert_ne!(base, first_bytes(hedged_rng(&mut ZeroRng, b"secreu", 3)));
+        assert_ne!(base, first_bytes(hedged_rng(&mut ZeroRng, b"secret", 2)));
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([1; 32]);
+        assert_ne!(base, first_bytes(hedged_rng(&mut rng, b"secret", 3)));
+    }
+
+    #[test]
+    fn test_broken_rng_does_not_leak_secret_relations() {
+        let shamir = crate::Shamir::new(2).unwrap();
+        let difference = |split: &dyn Fn(&[u8]) -> crate::Share| {
+            let a = split(b"attack at dawn");
+            let b = split(b"attack at dusk");
+            a.y.iter()
+                .zip(&b.y)
+                .map(|(a, b)| (*a - *b).0)
+                .collect::<Vec<u8>>()
+        };
+        let secret_difference: Vec<u8> = b"attack at dawn"
+            .iter()
+            .zip(b"attack at dusk")
+            .map(|(a, b)| a ^ b)
+            .collect();
+
+        // With a constant RNG, unhedged splits of two secrets share their
+        // coefficients, so any one share pair reveals how the secrets differ
+        let unhedged = difference(&|secret| {
+            let mut shares = shamir
+                .split_with_rng_unhedged(secret, &mut ZeroRng)
+                .unwrap();
+            shares.next().unwrap()
+        });
+        assert_eq!(unhedged[..14], secret_difference);
+
+        let hedged = difference(&|secret| {
+            let mut shares = shamir.split_with_rng(secret, &mut ZeroRng).unwrap();
+            shares.next().unwrap()
+        });
+        assert_ne!(hedged[..14], secret_difference);
+    }
+}
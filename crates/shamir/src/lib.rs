@@ -3,12 +3,25 @@
 //! This crate provides cryptographically secure secret sharing with integrity
 //! verification through CRC32 checksums.
 
+pub mod compat;
+pub mod dkg;
+pub mod field;
 pub mod gf256;
+mod hedge;
+#[cfg(feature = "mac")]
+pub mod mac;
+#[cfg(feature = "passphrase")]
+pub mod passphrase;
 pub mod poly;
+mod session;
 mod share;
+#[cfg(feature = "vss")]
+pub mod vss;
 
 use gf256::GF256;
 use hashbrown::HashSet;
+use rand::{CryptoRng, RngCore};
+pub use session::{Conflict, RecoverySession, Status};
 pub use share::Share;
 
 /// Errors that can occur during secret sharing operations.
@@ -26,6 +39,48 @@ pub enum ShamirError {
     ChecksumMismatch,
     /// Empty input provided
     EmptyInput,
+    /// Shares cannot be evaluated at x = 0, which is where the secret lives
+    InvalidXCoordinate,
+    /// The number of x-coordinates does not match the number of output buffers
+    ShareCountMismatch {
+        x_coordinates: usize,
+        buffers: usize,
+    },
+    /// An output buffer does not have the length of a serialized share
+    InvalidBufferLength { expected: usize, actual: usize },
+    /// Two interpolation points share an x-coordinate
+    DuplicatePoints,
+    /// A polynomial does not have the number of coefficients the threshold requires
+    PolynomialLengthMismatch {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// A polynomial's leading coefficient is zero, so its degree is too low
+    ZeroLeadingCoefficient(usize),
+    /// Matrix or vector dimensions are incompatible with the operation
+    DimensionMismatch,
+    /// The matrix is not invertible
+    SingularMatrix,
+    /// The linear system has no solution
+    InconsistentSystem,
+    /// The salt for passphrase-derived splitting is too short
+    InvalidSalt { min: usize },
+    /// The key derivation parameters are out of range
+    InvalidKdfParams,
+    /// A participant id is outside `1..=parties`, or a message was addressed
+    /// to another participant
+    InvalidParticipant(u8),
+    /// A participant received two deals from the same dealer
+    DuplicateDeal(u8),
+    /// Shares combined arithmetically have different x-coordinates
+    XCoordinateMismatch { left: u8, right: u8 },
+    /// A share does not match the dealer's published commitments
+    CommitmentMismatch(u8),
+    /// Serialized shares or commitments are malformed
+    InvalidEncoding,
+    /// A share's MAC tag does not match, so it was altered after dealing
+    AuthenticationFailed(u8),
 }
 
 impl std::fmt::Display for ShamirError {
@@ -45,6 +100,61 @@ impl std::fmt::Display for ShamirError {
                 write!(f, "Checksum verification failed - data may be corrupted")
             }
             Self::EmptyInput => write!(f, "Cannot process empty input"),
+            Self::InvalidXCoordinate => {
+                write!(
+                    f,
+                    "Shares cannot use x-coordinate 0, it would reveal the secret"
+                )
+            }
+            Self::ShareCountMismatch {
+                x_coordinates,
+                buffers,
+            } => write!(
+                f,
+                "Got {} x-coordinates but {} output buffers",
+                x_coordinates, buffers
+            ),
+            Self::InvalidBufferLength { expected, actual } => write!(
+                f,
+                "Output buffer must be {} bytes long, but is {} bytes",
+                expected, actual
+            ),
+            Self::DuplicatePoints => {
+                write!(f, "Interpolation points must have distinct x-coordinates")
+            }
+            Self::PolynomialLengthMismatch {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Polynomial {} has {} coefficients but expected {}",
+                index, actual, expected
+            ),
+            Self::ZeroLeadingCoefficient(index) => {
+                write!(f, "Polynomial {} has zero leading coefficient", index)
+            }
+            Self::DimensionMismatch => write!(f, "Matrix dimensions do not match"),
+            Self::SingularMatrix => write!(f, "Matrix is singular"),
+            Self::InconsistentSystem => write!(f, "Linear system has no solution"),
+            Self::InvalidSalt { min } => write!(f, "Salt must be at least {} bytes long", min),
+            Self::InvalidKdfParams => write!(f, "Invalid key derivation parameters"),
+            Self::InvalidParticipant(id) => write!(f, "Unexpected participant id: {}", id),
+            Self::DuplicateDeal(id) => write!(f, "Duplicate deal from participant {}", id),
+            Self::XCoordinateMismatch { left, right } => write!(
+                f,
+                "Cannot combine shares with x-coordinates {} and {}",
+                left, right
+            ),
+            Self::CommitmentMismatch(x) => write!(
+                f,
+                "Share with x-coordinate {} does not match the dealer's commitments",
+                x
+            ),
+            Self::InvalidEncoding => write!(f, "Malformed share or commitment encoding"),
+            Self::AuthenticationFailed(x) => {
+                write!(f, "Share with x-coordinate {} failed authentication", x)
+            }
         }
     }
 }
@@ -53,6 +163,9 @@ impl std::error::Error for ShamirError {}
 
 pub type Result<T> = std::result::Result<T, ShamirError>;
 
+/// Length of the CRC32 checksum appended to every secret before splitting.
+const CHECKSUM_LEN: usize = 4;
+
 /// Implements Shamir's Secret Sharing over GF(256).
 ///
 /// This struct provides methods to split secrets into shares and recover them
@@ -93,11 +206,30 @@ impl Shamir {
     /// Maximum number of shares that can be generated (255).
     pub const MAX_SHARES: u8 = 255;
 
+    /// Returns the length of a serialized share for a secret of `secret_len` bytes.
+    ///
+    /// A serialized share is the x-coordinate followed by one y-value per byte of
+    /// the secret and of its 4-byte checksum, as produced by [`Share::to_bytes`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fractus_shamir::Shamir;
+    /// assert_eq!(Shamir::share_len(16), 21);
+    /// ```
+    pub const fn share_len(secret_len: usize) -> usize {
+        1 + secret_len + CHECKSUM_LEN
+    }
+
     /// Splits a secret into shares using the provided random number generator.
     ///
     /// This is the core method for generating shares. It appends a CRC32 checksum
     /// to the secret for integrity verification during recovery.
     ///
+    /// The coefficients are hedged: they are drawn from a stream seeded with a
+    /// hash of fresh RNG output, the secret and the threshold, so a weak or
+    /// compromised RNG alone does not make them predictable. Seeded RNGs still
+    /// give reproducible shares.
+    ///
     /// # Arguments
     /// * `secret` - The secret to split into shares
     /// * `rng` - Random number generator for polynomial coefficients
@@ -121,7 +253,26 @@ impl Shamir {
     ///     .collect();
     /// assert_eq!(shares.len(), 5);
     /// ```
-    pub fn split_with_rng<R: rand::Rng>(
+    pub fn split_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        secret: &[u8],
+        rng: &mut R,
+    ) -> Result<impl Iterator<Item = Share> + use<R>> {
+        let mut hedged = hedge::hedged_rng(rng, secret, self.threshold);
+        self.split_with_rng_unhedged(secret, &mut hedged)
+    }
+
+    /// Splits a secret using the RNG's output directly as coefficient randomness.
+    ///
+    /// This is the opt-out from the hedging in [`Shamir::split_with_rng`]: the
+    /// shares are only as unpredictable as `rng`, which need not be a
+    /// cryptographic RNG. It exists for reproducible fixtures and for callers
+    /// that supply their own trusted RNG and want unhedged shares; never use it
+    /// with an RNG whose output could be guessed.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::EmptyInput` if the secret is empty.
+    pub fn split_with_rng_unhedged<R: rand::Rng>(
         &self,
         secret: &[u8],
         rng: &mut R,
@@ -131,15 +282,125 @@ impl Shamir {
         }
 
         let checksum = crc32fast::hash(secret).to_be_bytes();
-        let secret_with_checksum = [secret, &checksum].concat();
 
         // Generate a random polynomial for each byte in the secret+checksum
-        let polys: Vec<_> = secret_with_checksum
-            .into_iter()
-            .map(|byte| poly::random_polynomial(GF256(byte), self.threshold, rng))
-            .collect();
+        let coefficients =
+            poly::Coefficients::random_parts(&[secret, &checksum], self.threshold, rng);
+
+        Ok((1..=u8::MAX).map(GF256).map(move |x| {
+            let mut y = vec![0u8; coefficients.len()];
+            coefficients.evaluate_into(x, &mut y);
+            Share::new(x, y.into_iter().map(GF256).collect())
+        }))
+    }
 
-        Ok(poly::evaluator(polys))
+    /// Splits a secret directly into caller-provided buffers.
+    ///
+    /// Each buffer receives the serialized share for the x-coordinate at the same
+    /// position in `xs`, in the format of [`Share::to_bytes`], and must be exactly
+    /// [`Shamir::share_len`] bytes long. Apart from the coefficient buffer shared
+    /// by all shares, nothing is allocated, so the buffers can be pre-allocated or
+    /// memory-mapped.
+    ///
+    /// For the same RNG state this produces the same shares as
+    /// [`Shamir::split_with_rng`].
+    ///
+    /// # Arguments
+    /// * `secret` - The secret to split into shares
+    /// * `xs` - Distinct, non-zero x-coordinates, one per output buffer
+    /// * `rng` - Random number generator for polynomial coefficients
+    /// * `outputs` - One buffer per share
+    ///
+    /// # Errors
+    /// * `ShamirError::EmptyInput` - The secret is empty
+    /// * `ShamirError::ShareCountMismatch` - `xs` and `outputs` differ in length
+    /// * `ShamirError::InvalidXCoordinate` - An x-coordinate is zero
+    /// * `ShamirError::DuplicateShares` - An x-coordinate appears more than once
+    /// * `ShamirError::InvalidBufferLength` - A buffer is not `share_len` bytes long
+    ///
+    /// # Examples
+    /// ```
+    /// use fractus_shamir::{Shamir, Share, gf256::GF256};
+    /// use rand_chacha::rand_core::SeedableRng;
+    ///
+    /// let shamir = Shamir::new(2).unwrap();
+    /// let mut rng = rand_chacha::ChaCha8Rng::from_seed([0x90; 32]);
+    /// let secret = b"Hello world!";
+    ///
+    /// let mut buffer = vec![0u8; 3 * Shamir::share_len(secret.len())];
+    /// let mut outputs: Vec<&mut [u8]> = buffer.chunks_mut(Shamir::share_len(secret.len())).collect();
+    /// shamir
+    ///     .split_into(secret, &[GF256(1), GF256(2), GF256(3)], &mut rng, &mut outputs)
+    ///     .unwrap();
+    ///
+    /// let shares: Vec<Share> = outputs.iter().map(|b| Share::from_bytes(b).unwrap()).collect();
+    /// assert_eq!(shamir.recover(&shares[1..]).unwrap(), secret);
+    /// ```
+    pub fn split_into<R: RngCore + CryptoRng>(
+        &self,
+        secret: &[u8],
+        xs: &[GF256],
+        rng: &mut R,
+        outputs: &mut [&mut [u8]],
+    ) -> Result<()> {
+        let mut hedged = hedge::hedged_rng(rng, secret, self.threshold);
+        self.split_into_unhedged(secret, xs, &mut hedged, outputs)
+    }
+
+    /// Splits a secret into caller-provided buffers without hedging.
+    ///
+    /// Produces the same shares as [`Shamir::split_with_rng_unhedged`]; see
+    /// there for when this is appropriate.
+    ///
+    /// # Errors
+    /// The same errors as [`Shamir::split_into`].
+    pub fn split_into_unhedged<R: rand::Rng>(
+        &self,
+        secret: &[u8],
+        xs: &[GF256],
+        rng: &mut R,
+        outputs: &mut [&mut [u8]],
+    ) -> Result<()> {
+        if secret.is_empty() {
+            return Err(ShamirError::EmptyInput);
+        }
+
+        if xs.len() != outputs.len() {
+            return Err(ShamirError::ShareCountMismatch {
+                x_coordinates: xs.len(),
+                buffers: outputs.len(),
+            });
+        }
+
+        let mut seen = [false; 256];
+        for &x in xs {
+            if x.is_zero() {
+                return Err(ShamirError::InvalidXCoordinate);
+            }
+            if core::mem::replace(&mut seen[x.0 as usize], true) {
+                return Err(ShamirError::DuplicateShares(x.0));
+            }
+        }
+
+        let expected = Self::share_len(secret.len());
+        if let Some(output) = outputs.iter().find(|output| output.len() != expected) {
+            return Err(ShamirError::InvalidBufferLength {
+                expected,
+                actual: output.len(),
+            });
+        }
+
+        let checksum = crc32fast::hash(secret).to_be_bytes();
+        let coefficients =
+            poly::Coefficients::random_parts(&[secret, &checksum], self.threshold, rng);
+
+        for (&x, output) in xs.iter().zip(outputs.iter_mut()) {
+            let (x_byte, y) = output.split_at_mut(1);
+            x_byte[0] = x.0;
+            coefficients.evaluate_into(x, y);
+        }
+
+        Ok(())
     }
 
     /// Splits a secret into shares using the thread-local random number generator.
@@ -202,67 +463,335 @@ impl Shamir {
         T::IntoIter: Iterator<Item = &'a Share>,
     {
         let shares: Vec<&Share> = shares.into_iter().collect();
+        validate(&shares, self.threshold)?;
 
-        if shares.is_empty() {
-            return Err(ShamirError::InsufficientShares {
-                required: self.threshold,
-                provided: 0,
-            });
-        }
+        // Take only the required number of shares for efficiency
+        let shares_for_recovery = &shares[..self.threshold as usize];
 
-        // Validate share consistency
-        let expected_len = shares[0].y.len();
-        let mut unique_x_coords = HashSet::new();
+        // Perform polynomial interpolation
+        strip_checksum(poly::interpolate(shares_for_recovery))
+    }
 
-        for share in &shares {
-            // Check length consistency
-            if share.y.len() != expected_len {
-                return Err(ShamirError::InconsistentShareLength);
-            }
+    /// Recovers a secret without verifying its embedded CRC32 checksum.
+    ///
+    /// Scaling a share by [`Share::linear_combination`] or `share * scalar`
+    /// scales its checksum too, so the result no longer verifies; this method
+    /// drops the checksum instead. Corruption cannot be detected, so prefer
+    /// [`Shamir::recover`] for shares that were only added together.
+    ///
+    /// # Errors
+    /// * `ShamirError::InsufficientShares` - Not enough shares provided
+    /// * `ShamirError::InconsistentShareLength` - Shares have different lengths
+    /// * `ShamirError::DuplicateShares` - Multiple shares with same x-coordinate
+    pub fn recover_unchecked<'a, T>(&self, shares: T) -> Result<Vec<u8>>
+    where
+        T: IntoIterator<Item = &'a Share>,
+    {
+        let shares: Vec<&Share> = shares.into_iter().collect();
+        validate(&shares, self.threshold)?;
 
-            // Check for duplicates
-            if !unique_x_coords.insert(share.x.0) {
-                return Err(ShamirError::DuplicateShares(share.x.0));
+        let mut recovered = poly::interpolate(&shares[..self.threshold as usize]);
+        recovered.truncate(recovered.len().saturating_sub(CHECKSUM_LEN));
+        Ok(recovered)
+    }
+
+    /// Recovers a secret split by another Shamir implementation over the
+    /// GF(2^8) field `F`.
+    ///
+    /// Other implementations do not append fractus' CRC32 checksum, so the
+    /// result is returned as interpolated and corruption cannot be detected.
+    /// Each share's x-coordinate and bytes are taken as-is; converting from the
+    /// other implementation's share encoding is up to the caller.
+    ///
+    /// # Errors
+    /// * `ShamirError::InsufficientShares` - Not enough shares provided
+    /// * `ShamirError::InconsistentShareLength` - Shares have different lengths
+    /// * `ShamirError::DuplicateShares` - Multiple shares with same x-coordinate
+    ///
+    /// # Examples
+    /// ```
+    /// use fractus_shamir::gf256::{GF256, Gf2p8Gfshare};
+    /// use fractus_shamir::{Shamir, Share};
+    ///
+    /// // Shares of the byte 7 over the field with reducing polynomial 0x11d
+    /// let shares = vec![
+    ///     Share::new(GF256(1), vec![GF256(0x87)]),
+    ///     Share::new(GF256(2), vec![GF256(0x1a)]),
+    /// ];
+    /// let shamir = Shamir::new(2).unwrap();
+    /// assert_eq!(shamir.recover_in::<Gf2p8Gfshare, _>(&shares).unwrap(), vec![7]);
+    /// ```
+    pub fn recover_in<'a, F, T>(&self, shares: T) -> Result<Vec<u8>>
+    where
+        F: field::Field + From<u8> + Into<u8>,
+        T: IntoIterator<Item = &'a Share>,
+    {
+        let shares: Vec<&Share> = shares.into_iter().collect();
+        validate(&shares, self.threshold)?;
+
+        Ok(poly::interpolate_in::<F, _>(
+            &shares[..self.threshold as usize],
+        ))
+    }
+
+    /// Recovers the secret and checks every supplied share against it.
+    ///
+    /// [`Shamir::recover`] only interpolates the first `threshold` shares, so a
+    /// corrupted surplus share goes unnoticed. This method additionally evaluates
+    /// the recovered polynomials at the x-coordinate of every other share and
+    /// reports which shares lie on them.
+    ///
+    /// A share that fails the check is corrupted or belongs to a different
+    /// secret. The shares used for interpolation are consistent by construction;
+    /// if one of them were corrupted, the checksum would almost certainly fail.
+    ///
+    /// # Errors
+    /// The same errors as [`Shamir::recover`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fractus_shamir::{Shamir, gf256::GF256};
+    /// use rand_chacha::rand_core::SeedableRng;
+    ///
+    /// let shamir = Shamir::new(2).unwrap();
+    /// let mut rng = rand_chacha::ChaCha8Rng::from_seed([0x90; 32]);
+    /// let mut shares: Vec<_> = shamir.split_with_rng(b"Hello world!", &mut rng)
+    ///     .unwrap()
+    ///     .take(4)
+    ///     .collect();
+    /// shares[3].y[0] = shares[3].y[0] + GF256(1);
+    ///
+    /// let (secret, report) = shamir.recover_verified(&shares).unwrap();
+    /// assert_eq!(&secret, b"Hello world!");
+    /// assert_eq!(report.consistent, vec![1, 2, 3]);
+    /// assert_eq!(report.inconsistent, vec![4]);
+    /// ```
+    pub fn recover_verified<'a, T>(&self, shares: T) -> Result<(Vec<u8>, VerificationReport)>
+    where
+        T: IntoIterator<Item = &'a Share>,
+        T::IntoIter: Iterator<Item = &'a Share>,
+    {
+        let shares: Vec<&Share> = shares.into_iter().collect();
+        validate(&shares, self.threshold)?;
+
+        let (basis, surplus) = shares.split_at(self.threshold as usize);
+        let secret = strip_checksum(poly::interpolate(basis))?;
+
+        let mut report = VerificationReport {
+            consistent: basis.iter().map(|share| share.x.0).collect(),
+            inconsistent: Vec::new(),
+        };
+        for share in surplus {
+            if poly::interpolate_at(basis, share.x) == gf256::as_bytes(&share.y) {
+                report.consistent.push(share.x.0);
+            } else {
+                report.inconsistent.push(share.x.0);
             }
         }
 
-        // Check if we have enough shares
-        if shares.len() < self.threshold as usize {
-            return Err(ShamirError::InsufficientShares {
-                required: self.threshold,
-                provided: shares.len(),
-            });
+        Ok((secret, report))
+    }
+
+    /// Recovers a secret without knowing the threshold, inferring it from the shares.
+    ///
+    /// Finds the lowest-degree polynomials that pass through the supplied
+    /// shares and checks the result against the embedded CRC32 checksum.
+    /// Because the leading coefficients of the dealer's polynomials are never
+    /// zero, the number of points needed to define them is the threshold the
+    /// shares were created with.
+    ///
+    /// For each candidate threshold `t`, the first `t` shares define the
+    /// polynomials and the others are checked against them. The candidate that
+    /// the most shares agree with wins, preferring the lowest threshold, so
+    /// corrupted surplus shares are outvoted and listed in
+    /// [`AutoRecovery::inconsistent`]. A threshold is only inferred when at
+    /// least one surplus share agrees with it; otherwise every share is
+    /// needed, and the result reports a threshold equal to the number of
+    /// shares.
+    ///
+    /// The first `threshold` shares, in the order supplied, must be intact:
+    /// a corrupted share among them leaves no polynomial that other shares
+    /// agree with, and recovery fails the checksum. Shares that lie on
+    /// polynomials of their own can only win by outnumbering the genuine ones.
+    ///
+    /// # Errors
+    /// * `ShamirError::InsufficientShares` - No shares provided
+    /// * `ShamirError::InconsistentShareLength` - Shares have different lengths
+    /// * `ShamirError::DuplicateShares` - Multiple shares with same x-coordinate
+    /// * `ShamirError::ChecksumMismatch` - Too few shares were supplied, or one
+    ///   of the first `threshold` is corrupted
+    ///
+    /// # Examples
+    /// ```
+    /// use fractus_shamir::Shamir;
+    /// use rand_chacha::rand_core::SeedableRng;
+    ///
+    /// let shamir = Shamir::new(3).unwrap();
+    /// let mut rng = rand_chacha::ChaCha8Rng::from_seed([0x90; 32]);
+    /// let shares: Vec<_> = shamir.split_with_rng(b"Hello world!", &mut rng)
+    ///     .unwrap()
+    ///     .take(5)
+    ///     .collect();
+    ///
+    /// let recovery = Shamir::recover_auto(&shares).unwrap();
+    /// assert_eq!(&recovery.secret, b"Hello world!");
+    /// assert_eq!(recovery.threshold, 3);
+    /// assert_eq!(recovery.redundancy, 2);
+    /// ```
+    pub fn recover_auto<'a, T>(shares: T) -> Result<AutoRecovery>
+    where
+        T: IntoIterator<Item = &'a Share>,
+        T::IntoIter: Iterator<Item = &'a Share>,
+    {
+        let shares: Vec<&Share> = shares.into_iter().collect();
+        validate(&shares, 1)?;
+
+        // Candidate thresholds with the surplus shares that agree with them,
+        // most agreeing shares first and the lowest threshold on ties
+        let mut candidates = Vec::new();
+        for t in 1..shares.len() {
+            let (basis, rest) = shares.split_at(t);
+            let (agreeing, inconsistent): (Vec<&&Share>, Vec<&&Share>) =
+                rest.iter().partition(|share| {
+                    poly::interpolate_at(basis, share.x) == gf256::as_bytes(&share.y)
+                });
+            if agreeing.is_empty() {
+                continue;
+            }
+            let complete = inconsistent.is_empty();
+            candidates.push((t, agreeing.len(), inconsistent));
+            // Every share agrees, which no higher threshold can improve on
+            if complete {
+                break;
+            }
+        }
+        candidates.sort_by_key(|(t, agreeing, _)| (core::cmp::Reverse(t + agreeing), *t));
+
+        for (t, agreeing, inconsistent) in candidates {
+            if let Ok(secret) = strip_checksum(poly::interpolate(&shares[..t])) {
+                return Ok(AutoRecovery {
+                    secret,
+                    threshold: u8::try_from(t)
+                        .expect("a basis is smaller than the 256 possible shares"),
+                    redundancy: agreeing,
+                    inconsistent: inconsistent.iter().map(|share| share.x.0).collect(),
+                });
+            }
         }
 
-        // Take only the required number of shares for efficiency
-        let shares_for_recovery: Vec<Share> = shares
-            .into_iter()
-            .take(self.threshold as usize)
-            .cloned()
-            .collect();
+        // No surplus share confirms any threshold, so every share is needed
+        let secret = strip_checksum(poly::interpolate(&shares))?;
+        Ok(AutoRecovery {
+            secret,
+            threshold: u8::try_from(shares.len()).map_err(|_| ShamirError::ChecksumMismatch)?,
+            redundancy: 0,
+            inconsistent: Vec::new(),
+        })
+    }
+}
 
-        // Perform polynomial interpolation
-        let mut recovered_with_checksum = poly::interpolate(&shares_for_recovery);
+/// Checks that shares have matching lengths, distinct x-coordinates and that
+/// there are at least `threshold` of them.
+fn validate(shares: &[&Share], threshold: u8) -> Result<()> {
+    if shares.is_empty() {
+        return Err(ShamirError::InsufficientShares {
+            required: threshold,
+            provided: 0,
+        });
+    }
 
-        // Verify we have enough bytes for the checksum
-        if recovered_with_checksum.len() < 4 {
-            return Err(ShamirError::ChecksumMismatch);
-        }
+    // Validate share consistency
+    let expected_len = shares[0].y.len();
+    let mut unique_x_coords = HashSet::new();
 
-        // Split the recovered data and checksum
-        let checksum_bytes = recovered_with_checksum.split_off(recovered_with_checksum.len() - 4);
-        let secret = recovered_with_checksum;
+    for share in shares {
+        // Check length consistency
+        if share.y.len() != expected_len {
+            return Err(ShamirError::InconsistentShareLength);
+        }
 
-        // Verify checksum
-        let expected_checksum = crc32fast::hash(&secret).to_be_bytes();
-        if checksum_bytes != expected_checksum {
-            return Err(ShamirError::ChecksumMismatch);
+        // Check for duplicates
+        if !unique_x_coords.insert(share.x.0) {
+            return Err(ShamirError::DuplicateShares(share.x.0));
         }
+    }
+
+    // Check if we have enough shares
+    if shares.len() < threshold as usize {
+        return Err(ShamirError::InsufficientShares {
+            required: threshold,
+            provided: shares.len(),
+        });
+    }
 
-        Ok(secret)
+    Ok(())
+}
+
+/// A secret recovered by [`Shamir::recover_auto`], with the inferred threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoRecovery {
+    /// The recovered secret
+    pub secret: Vec<u8>,
+    /// The number of shares needed to recover the secret
+    pub threshold: u8,
+    /// How many of the supplied shares could be lost while still recovering
+    pub redundancy: usize,
+    /// X-coordinates of supplied shares that do not lie on the recovered
+    /// polynomials, in the order they were supplied
+    pub inconsistent: Vec<u8>,
+}
+
+impl AutoRecovery {
+    /// Returns true if surplus shares confirmed the inferred threshold.
+    ///
+    /// Without surplus shares the threshold can only be bounded from above by
+    /// the number of shares supplied.
+    pub fn is_threshold_confirmed(&self) -> bool {
+        self.redundancy > 0
     }
 }
 
+/// Outcome of checking every supplied share against the recovered polynomials.
+///
+/// Returned by [`Shamir::recover_verified`]. Shares are listed by x-coordinate,
+/// in the order they were supplied.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VerificationReport {
+    /// Shares that lie on the recovered polynomials
+    pub consistent: Vec<u8>,
+    /// Shares that do not lie on the recovered polynomials
+    pub inconsistent: Vec<u8>,
+}
+
+impl VerificationReport {
+    /// Returns true if every supplied share is consistent with the others.
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistent.is_empty()
+    }
+}
+
+/// Splits interpolated data into the secret and its trailing CRC32 checksum,
+/// verifying that they match.
+fn strip_checksum(mut recovered_with_checksum: Vec<u8>) -> Result<Vec<u8>> {
+    // Verify we have enough bytes for the checksum
+    if recovered_with_checksum.len() < CHECKSUM_LEN {
+        return Err(ShamirError::ChecksumMismatch);
+    }
+
+    // Split the recovered data and checksum
+    let checksum_bytes =
+        recovered_with_checksum.split_off(recovered_with_checksum.len() - CHECKSUM_LEN);
+    let secret = recovered_with_checksum;
+
+    // Verify checksum
+    let expected_checksum = crc32fast::hash(&secret).to_be_bytes();
+    if checksum_bytes != expected_checksum {
+        return Err(ShamirError::ChecksumMismatch);
+    }
+
+    Ok(secret)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -610,7 +1139,7 @@ mod tests {
         // Test various threshold values
         for threshold in [1, 2, 10, 50, 100, 200, 255] {
             let shamir = Shamir::new(threshold).unwrap();
-            let mut rng = rand_chacha::ChaCha8Rng::from_seed([threshold as u8; 32]);
+            let mut rng = rand_chacha::ChaCha8Rng::from_seed([threshold; 32]);
             let secret = format!("threshold {}", threshold).into_bytes();
 
             let shares: Vec<_> = shamir
@@ -696,6 +1225,254 @@ mod tests {
         assert_eq!(&recovered, secret);
     }
 
+    #[test]
+    fn test_split_into_matches_split_with_rng() {
+        let shamir = Shamir::new(3).unwrap();
+        let secret = b"zero allocation split";
+        let xs: Vec<GF256> = (1..=5).map(GF256).collect();
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([20; 32]);
+        let expected: Vec<_> = shamir
+            .split_with_rng(secret, &mut rng)
+            .unwrap()
+            .take(5)
+            .collect();
+
+        let share_len = Shamir::share_len(secret.len());
+        let mut buffer = vec![0u8; xs.len() * share_len];
+        let mut outputs: Vec<&mut [u8]> = buffer.chunks_mut(share_len).collect();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([20; 32]);
+        shamir
+            .split_into(secret, &xs, &mut rng, &mut outputs)
+            .unwrap();
+
+        for (output, share) in outputs.iter().zip(&expected) {
+            assert_eq!(output.to_vec(), share.to_bytes());
+        }
+    }
+
+    #[test]
+    fn test_split_into_arbitrary_x_coordinates() {
+        let shamir = Shamir::new(2).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([21; 32]);
+        let secret = b"sparse x";
+        let xs = [GF256(200), GF256(17), GF256(255)];
+
+        let share_len = Shamir::share_len(secret.len());
+        let mut buffers = vec![vec![0u8; share_len]; xs.len()];
+        let mut outputs: Vec<&mut [u8]> = buffers.iter_mut().map(Vec::as_mut_slice).collect();
+        shamir
+            .split_into(secret, &xs, &mut rng, &mut outputs)
+            .unwrap();
+
+        let shares: Vec<Share> = buffers
+            .iter()
+            .map(|b| Share::from_bytes(b).unwrap())
+            .collect();
+        assert_eq!(shares[2].x(), GF256(255));
+        assert_eq!(&shamir.recover(&shares[1..]).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_split_into_validation() {
+        let shamir = Shamir::new(2).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([22; 32]);
+        let secret = b"validate";
+        let share_len = Shamir::share_len(secret.len());
+        let mut a = vec![0u8; share_len];
+        let mut b = vec![0u8; share_len];
+        let mut short = vec![0u8; share_len - 1];
+
+        assert_eq!(
+            shamir.split_into(secret, &[GF256(1)], &mut rng, &mut [&mut a, &mut b]),
+            Err(ShamirError::ShareCountMismatch {
+                x_coordinates: 1,
+                buffers: 2
+            })
+        );
+        assert_eq!(
+            shamir.split_into(
+                secret,
+                &[GF256(0), GF256(1)],
+                &mut rng,
+                &mut [&mut a, &mut b]
+            ),
+            Err(ShamirError::InvalidXCoordinate)
+        );
+        assert_eq!(
+            shamir.split_into(
+                secret,
+                &[GF256(3), GF256(3)],
+                &mut rng,
+                &mut [&mut a, &mut b]
+            ),
+            Err(ShamirError::DuplicateShares(3))
+        );
+        assert_eq!(
+            shamir.split_into(
+                secret,
+                &[GF256(1), GF256(2)],
+                &mut rng,
+                &mut [&mut a, &mut short]
+            ),
+            Err(ShamirError::InvalidBufferLength {
+                expected: share_len,
+                actual: share_len - 1
+            })
+        );
+        assert_eq!(
+            shamir.split_into(b"", &[GF256(1)], &mut rng, &mut [&mut a]),
+            Err(ShamirError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_seeded_split_is_stable() {
+        // Pins the mapping from a seeded RNG to shares, which must not depend on
+        // whether the `parallel` feature is enabled
+        let shamir = Shamir::new(3).unwrap();
+        let secret: Vec<u8> = (0..3 * poly::CHUNK_LEN + 5)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let digest = |shares: &mut dyn Iterator<Item = Share>| {
+            shares
+                .take(4)
+                .fold(crc32fast::Hasher::new(), |mut hasher, share| {
+                    hasher.update(&share.to_bytes());
+                    hasher
+                })
+                .finalize()
+        };
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([23; 32]);
+        let mut unhedged = shamir.split_with_rng_unhedged(&secret, &mut rng).unwrap();
+        assert_eq!(digest(&mut unhedged), 0x70c6_b66b);
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([23; 32]);
+        let mut hedged = shamir.split_with_rng(&secret, &mut rng).unwrap();
+        assert_eq!(digest(&mut hedged), 0x4945_0942);
+    }
+
+    #[test]
+    fn test_recover_verified_reports_surplus_shares() {
+        let shamir = Shamir::new(3).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([24; 32]);
+        let secret = b"verify every share";
+
+        let mut shares: Vec<_> = shamir
+            .split_with_rng(secret, &mut rng)
+            .unwrap()
+            .take(6)
+            .collect();
+
+        let (recovered, report) = shamir.recover_verified(&shares).unwrap();
+        assert_eq!(&recovered, secret);
+        assert_eq!(report.consistent, vec![1, 2, 3, 4, 5, 6]);
+        assert!(report.is_consistent());
+
+        // Corrupt two surplus shares
+        shares[3].y[5] = shares[3].y[5] + GF256(0x11);
+        shares[5].y[0] = shares[5].y[0] + GF256(0x80);
+
+        let (recovered, report) = shamir.recover_verified(&shares).unwrap();
+        assert_eq!(&recovered, secret);
+        assert_eq!(report.consistent, vec![1, 2, 3, 5]);
+        assert_eq!(report.inconsistent, vec![4, 6]);
+        assert!(!report.is_consistent());
+
+        // Plain recovery does not notice
+        assert_eq!(&shamir.recover(&shares).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_recover_verified_validation() {
+        let shamir = Shamir::new(3).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([25; 32]);
+        let shares: Vec<_> = shamir
+            .split_with_rng(b"short", &mut rng)
+            .unwrap()
+            .take(2)
+            .collect();
+
+        assert_eq!(
+            shamir.recover_verified(&shares),
+            Err(ShamirError::InsufficientShares {
+                required: 3,
+                provided: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_recover_auto_infers_threshold() {
+        for threshold in [1u8, 2, 3, 7] {
+            let shamir = Shamir::new(threshold).unwrap();
+            let mut rng = rand_chacha::ChaCha8Rng::from_seed([threshold; 32]);
+            let secret = b"infer the threshold";
+            let shares: Vec<_> = shamir
+                .split_with_rng(secret, &mut rng)
+                .unwrap()
+                .take(threshold as usize + 3)
+                .collect();
+
+            let recovery = Shamir::recover_auto(&shares).unwrap();
+            assert_eq!(&recovery.secret, secret);
+            assert_eq!(recovery.threshold, threshold);
+            assert_eq!(recovery.redundancy, 3);
+            assert!(recovery.is_threshold_confirmed());
+
+            // Exactly threshold shares still recover, but cannot confirm it
+            let recovery = Shamir::recover_auto(&shares[3..]).unwrap();
+            assert_eq!(&recovery.secret, secret);
+            assert_eq!(recovery.threshold, threshold);
+            assert!(!recovery.is_threshold_confirmed());
+        }
+    }
+
+    #[test]
+    fn test_recover_auto_failures() {
+        let shamir = Shamir::new(4).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([26; 32]);
+        let mut shares: Vec<_> = shamir
+            .split_with_rng(b"auto failures", &mut rng)
+            .unwrap()
+            .take(6)
+            .collect();
+
+        // Too few shares interpolate to garbage
+        assert_eq!(
+            Shamir::recover_auto(&shares[..3]),
+            Err(ShamirError::ChecksumMismatch)
+        );
+
+        // A corrupted surplus share is outvoted by the others
+        shares[5].y[1] = shares[5].y[1] + GF256(1);
+        let recovery = Shamir::recover_auto(&shares).unwrap();
+        assert_eq!(&recovery.secret, b"auto failures");
+        assert_eq!(recovery.threshold, 4);
+        assert_eq!(recovery.redundancy, 1);
+        assert_eq!(recovery.inconsistent, vec![6]);
+
+        // Without a surplus share to outvote it, a corrupted share is fatal
+        assert_eq!(
+            Shamir::recover_auto(&shares[1..]),
+            Err(ShamirError::ChecksumMismatch)
+        );
+
+        // So is a corrupted share among the first threshold
+        shares.swap(0, 5);
+        assert_eq!(
+            Shamir::recover_auto(&shares),
+            Err(ShamirError::ChecksumMismatch)
+        );
+
+        let empty: Vec<Share> = vec![];
+        assert!(matches!(
+            Shamir::recover_auto(&empty),
+            Err(ShamirError::InsufficientShares { provided: 0, .. })
+        ));
+    }
+
     #[test]
     fn test_unicode_data() {
         let shamir = Shamir::new(3).unwrap();
@@ -717,4 +1494,63 @@ mod tests {
         let recovered_string = String::from_utf8(recovered).unwrap();
         assert_eq!(recovered_string, "Hello 世界! 🚀");
     }
+
+    fn share_from_hex(x: u8, hex: &str) -> Share {
+        let y = (0..hex.len())
+            .step_by(2)
+            .map(|i| GF256(u8::from_str_radix(&hex[i..i + 2], 16).unwrap()))
+            .collect();
+        Share::new(GF256(x), y)
+    }
+
+    #[test]
+    fn test_recover_in_0x11d_field() {
+        use gf256::Gf2p8Gfshare;
+
+        // A 3-of-n split of "libgfshare interop" over GF(2^8)/0x11d, computed
+        // with a bitwise reference that shares no code with the crate. Shares
+        // made by another implementation are recovered in the compat tests.
+        let shares = [
+            share_from_hex(0x13, "2ede08e826e812a53a62386d5168d1b3be0d"),
+            share_from_hex(0x8c, "dd1c3cab958940c75f0002589ad7fdbc41cc"),
+            share_from_hex(0xf1, "142db29eff4fe6673a6bba754d23c83bfe46"),
+            share_from_hex(0x42, "5ef254095a62ab7b85274840dc232f497a42"),
+        ];
+        let shamir = Shamir::new(3).unwrap();
+
+        for subset in [[0, 1, 2], [1, 2, 3], [3, 0, 2]] {
+            let picked: Vec<&Share> = subset.iter().map(|&i| &shares[i]).collect();
+            assert_eq!(
+                shamir.recover_in::<Gf2p8Gfshare, _>(picked).unwrap(),
+                b"libgfshare interop"
+            );
+        }
+
+        // The AES field does not recover them
+        assert_ne!(
+            poly::interpolate(&shares[..3]),
+            b"libgfshare interop".to_vec()
+        );
+        assert_eq!(
+            shamir.recover_in::<Gf2p8Gfshare, _>(&shares[..2]),
+            Err(ShamirError::InsufficientShares {
+                required: 3,
+                provided: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_recover_in_aes_field_matches_recover() {
+        let shamir = Shamir::new(2).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([35; 32]);
+        let shares: Vec<_> = shamir
+            .split_with_rng(b"same field", &mut rng)
+            .unwrap()
+            .take(2)
+            .collect();
+
+        let raw = shamir.recover_in::<gf256::Gf2p8Aes, _>(&shares).unwrap();
+        assert_eq!(strip_checksum(raw).unwrap(), b"same field");
+    }
 }
@@ -0,0 +1,496 @@
+//! Information-theoretic share authentication in the style of Rabin and
+//! Ben-Or.
+//!
+//! The CRC32 embedded in every secret only shows that *some* share was bad,
+//! and only after recovery. Here the dealer also gives every holder a one-time
+//! MAC key for each other holder's share, and attaches to each share one tag
+//! per verifier. When shares are pooled, each holder checks every other share
+//! with its own keys ([`AuthenticatedShare::verify`]), and a share a majority
+//! of the others reject is blamed on its holder ([`check`]).
+//!
+//! Tags are computed over GF(2^128) modulo x^128 + x^7 + x^2 + x + 1 with a
+//! key `(b, c)` as
+//!
+//! ```text
+//! tag = c + M_1·b^n + M_2·b^(n-1) + ... + M_n·b
+//! ```
+//!
+//! where `M_1..M_n` are the share's bytes (x-coordinate first) in zero-padded
+//! 16-byte big-endian blocks, followed by a block holding the byte length.
+//! Without the key, a changed share passes with probability at most
+//! `n / 2^128`, whatever the attacker's computing power. A key must only ever
+//! authenticate one share, which is why each holder gets its own key for
+//! every other share.
+//!
+//! Checking needs an honest majority among the pooled shares: a cheating holder
+//! can also lie about the shares it verifies, but is outvoted.
+//!
+//! # Examples
+//! ```
+//! use fractus_shamir::Shamir;
+//! use fractus_shamir::gf256::GF256;
+//!
+//! let shamir = Shamir::new(2).unwrap();
+//! let mut shares = shamir
+//!     .split_authenticated(b"secret", 3, &mut rand::thread_rng())
+//!     .unwrap();
+//!
+//! // The holder of share 3 tampers with it before recovery
+//! shares[2].share.y[0] = shares[2].share.y[0] + GF256(1);
+//!
+//! let (secret, report) = shamir.recover_authenticated(&shares).unwrap();
+//! assert_eq!(secret, b"secret");
+//! assert_eq!(report.rejected, vec![3]);
+//! ```
+use rand::{CryptoRng, RngCore};
+
+use super::{Result, Shamir, ShamirError, Share};
+
+/// Length of a tag, and of each half of a key, in bytes.
+pub const TAG_LEN: usize = 16;
+
+/// Serialization format version of [`Authentication`].
+const VERSION: u8 = 1;
+
+/// A MAC tag over one share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tag(pub [u8; TAG_LEN]);
+
+/// A one-time key to check the tag on one other holder's share.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MacKey {
+    b: u128,
+    c: u128,
+}
+
+impl core::fmt::Debug for MacKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("MacKey(..)")
+    }
+}
+
+impl Drop for MacKey {
+    fn drop(&mut self) {
+        self.b = 0;
+        self.c = 0;
+    }
+}
+
+/// The authentication data that travels with one share: its tags for the
+/// other holders, and the keys to check theirs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Authentication {
+    /// Tags on this share, by the x-coordinate of the holder who checks them
+    pub tags: Vec<(u8, Tag)>,
+    /// Keys for checking other shares, by the x-coordinate of their holder
+    pub keys: Vec<(u8, MacKey)>,
+}
+
+/// A share together with its authentication data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedShare {
+    /// The share itself, as produced by [`Shamir::split`]
+    pub share: Share,
+    /// Tags on the share and keys for the other holders' shares
+    pub auth: Authentication,
+}
+
+/// Outcome of cross-checking a pool of authenticated shares.
+///
+/// Returned by [`check`] and [`Shamir::recover_authenticated`]. Shares are
+/// listed by x-coordinate, in the order they were supplied.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AuthenticationReport {
+    /// Shares that a majority of the others accepted
+    pub accepted: Vec<u8>,
+    /// Shares that a majority of the others rejected
+    pub rejected: Vec<u8>,
+    /// Every failed check, as (verifier, rejected share)
+    pub accusations: Vec<(u8, u8)>,
+}
+
+impl AuthenticationReport {
+    /// Returns true if every share passed.
+    pub fn is_authentic(&self) -> bool {
+        self.rejected.is_empty()
+    }
+}
+
+impl MacKey {
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 2 * TAG_LEN];
+        rng.fill_bytes(&mut bytes);
+        let key = Self::from_bytes(&bytes);
+        bytes.fill(0);
+        key
+    }
+
+    fn from_bytes(bytes: &[u8; 2 * TAG_LEN]) -> Self {
+        let (b, c) = bytes.split_at(TAG_LEN);
+        Self {
+            b: u128::from_be_bytes(b.try_into().unwrap()),
+            c: u128::from_be_bytes(c.try_into().unwrap()),
+        }
+    }
+
+    fn tag(&self, share: &Share) -> Tag {
+        let bytes = share.to_bytes();
+        let mut acc = 0u128;
+        for block in bytes.chunks(TAG_LEN) {
+            let mut padded = [0u8; TAG_LEN];
+            padded[..block.len()].copy_from_slice(block);
+            acc = gf128_mul(acc ^ u128::from_be_bytes(padded), self.b);
+        }
+        acc = gf128_mul(acc ^ bytes.len() as u128, self.b);
+        Tag((acc ^ self.c).to_be_bytes())
+    }
+}
+
+impl Authentication {
+    /// Returns the tag on this share for the holder of share `verifier`.
+    pub fn tag_for(&self, verifier: u8) -> Option<&Tag> {
+        find(&self.tags, verifier)
+    }
+
+    /// Returns the key for checking the share with x-coordinate `holder`.
+    pub fn key_for(&self, holder: u8) -> Option<&MacKey> {
+        find(&self.keys, holder)
+    }
+
+    /// Serializes the authentication data.
+    ///
+    /// The format is: [version, tag count, (x, tag)..., key count,
+    /// (x, b, c)...], with 16-byte tags and key halves.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            3 + self.tags.len() * (1 + TAG_LEN) + self.keys.len() * (1 + 2 * TAG_LEN),
+        );
+        bytes.push(VERSION);
+        bytes.push(self.tags.len() as u8);
+        for (x, tag) in &self.tags {
+            bytes.push(*x);
+            bytes.extend_from_slice(&tag.0);
+        }
+        bytes.push(self.keys.len() as u8);
+        for (x, key) in &self.keys {
+            bytes.push(*x);
+            bytes.extend_from_slice(&key.b.to_be_bytes());
+            bytes.extend_from_slice(&key.c.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Deserializes authentication data written by [`Authentication::to_bytes`].
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidEncoding` if the bytes are malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader(bytes);
+        if reader.take::<1>()? != [VERSION] {
+            return Err(ShamirError::InvalidEncoding);
+        }
+
+        let [tag_count] = reader.take()?;
+        let tags = (0..tag_count)
+            .map(|_| Ok((reader.take::<1>()?[0], Tag(reader.take()?))))
+            .collect::<Result<_>>()?;
+        let [key_count] = reader.take()?;
+        let keys = (0..key_count)
+            .map(|_| Ok((reader.take::<1>()?[0], MacKey::from_bytes(&reader.take()?))))
+            .collect::<Result<_>>()?;
+
+        if !reader.0.is_empty() {
+            return Err(ShamirError::InvalidEncoding);
+        }
+        Ok(Self { tags, keys })
+    }
+}
+
+impl AuthenticatedShare {
+    /// Checks `other`'s share with this holder's key for it.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::AuthenticationFailed` with `other`'s x-coordinate
+    /// if its tag does not match, or if either side lacks the key or tag
+    /// (for instance because the shares come from different splits).
+    pub fn verify(&self, other: &AuthenticatedShare) -> Result<()> {
+        let x = other.share.x.0;
+        let key = self.auth.key_for(x);
+        let tag = other.auth.tag_for(self.share.x.0);
+        match (key, tag) {
+            (Some(key), Some(tag)) if constant_time_eq(&key.tag(&other.share).0, &tag.0) => Ok(()),
+            _ => Err(ShamirError::AuthenticationFailed(x)),
+        }
+    }
+}
+
+impl Shamir {
+    /// Splits a secret into `shares` authenticated shares with x-coordinates
+    /// `1..=shares`.
+    ///
+    /// The shares are those [`Shamir::split_with_rng`] would produce; every
+    /// holder additionally gets a tag for and a key from each other holder.
+    ///
+    /// # Errors
+    /// * `ShamirError::EmptyInput` - The secret is empty
+    /// * `ShamirError::InvalidThreshold` - `shares` is below the threshold
+    pub fn split_authenticated<R: RngCore + CryptoRng>(
+        &self,
+        secret: &[u8],
+        shares: u8,
+        rng: &mut R,
+    ) -> Result<Vec<AuthenticatedShare>> {
+        if shares < self.threshold {
+            return Err(ShamirError::InvalidThreshold);
+        }
+
+        let mut authenticated: Vec<AuthenticatedShare> = self
+            .split_with_rng(secret, rng)?
+            .take(shares as usize)
+            .map(|share| AuthenticatedShare {
+                share,
+                auth: Authentication::default(),
+            })
+            .collect();
+
+        for holder in 0..authenticated.len() {
+            for verifier in 0..authenticated.len() {
+                if holder == verifier {
+                    continue;
+                }
+                let key = MacKey::random(rng);
+                let tag = key.tag(&authenticated[holder].share);
+                let holder_x = authenticated[holder].share.x.0;
+                let verifier_x = authenticated[verifier].share.x.0;
+                authenticated[holder].auth.tags.push((verifier_x, tag));
+                authenticated[verifier].auth.keys.push((holder_x, key));
+            }
+        }
+
+        Ok(authenticated)
+    }
+
+    /// Cross-checks authenticated shares and recovers the secret from those a
+    /// majority of the others accept.
+    ///
+    /// # Errors
+    /// * `ShamirError::InsufficientShares` - Fewer than `threshold` shares were accepted
+    /// * The other errors of [`Shamir::recover`]
+    pub fn recover_authenticated<'a, T>(&self, shares: T) -> Result<(Vec<u8>, AuthenticationReport)>
+    where
+        T: IntoIterator<Item = &'a AuthenticatedShare>,
+    {
+        let shares: Vec<&AuthenticatedShare> = shares.into_iter().collect();
+        let report = check(shares.iter().copied());
+
+        let accepted: Vec<&Share> = shares
+            .iter()
+            .filter(|share| report.accepted.contains(&share.share.x.0))
+            .map(|share| &share.share)
+            .collect();
+        let secret = self.recover(accepted)?;
+
+        Ok((secret, report))
+    }
+}
+
+/// Has every share check every other one, and rejects the shares that a
+/// strict majority of the others fail.
+///
+/// A single share has nobody to check it and is accepted. Shares are matched
+/// by x-coordinate, so duplicates should be removed first.
+pub fn check<'a, T>(shares: T) -> AuthenticationReport
+where
+    T: IntoIterator<Item = &'a AuthenticatedShare>,
+{
+    let shares: Vec<&AuthenticatedShare> = shares.into_iter().collect();
+    let mut report = AuthenticationReport::default();
+
+    for checked in &shares {
+        let x = checked.share.x.0;
+        let mut failures = 0;
+        for verifier in shares.iter().filter(|verifier| verifier.share.x.0 != x) {
+            if verifier.verify(checked).is_err() {
+                failures += 1;
+                report.accusations.push((verifier.share.x.0, x));
+            }
+        }
+
+        if 2 * failures > shares.len() - 1 {
+            report.rejected.push(x);
+        } else {
+            report.accepted.push(x);
+        }
+    }
+
+    report
+}
+
+fn find<T>(entries: &[(u8, T)], x: u8) -> Option<&T> {
+    entries
+        .iter()
+        .find(|(entry_x, _)| *entry_x == x)
+        .map(|(_, value)| value)
+}
+
+fn constant_time_eq(a: &[u8; TAG_LEN], b: &[u8; TAG_LEN]) -> bool {
+    a.iter().zip(b).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+/// Multiplication in GF(2^128) modulo x^128 + x^7 + x^2 + x + 1, with the
+/// most significant bit of the u128 as the x^127 coefficient.
+fn gf128_mul(mut a: u128, b: u128) -> u128 {
+    let mut product = 0u128;
+    for i in 0..128 {
+        product ^= a & 0u128.wrapping_sub((b >> i) & 1);
+        let carry = 0u128.wrapping_sub(a >> 127);
+        a = (a << 1) ^ (carry & 0x87);
+    }
+    product
+}
+
+/// A cursor over serialized authentication data.
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.0.len() < N {
+            return Err(ShamirError::InvalidEncoding);
+        }
+        let (head, rest) = self.0.split_at(N);
+        self.0 = rest;
+        Ok(head.try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf256::GF256;
+    use rand_chacha::rand_core::SeedableRng;
+
+    fn split(threshold: u8, shares: u8) -> Vec<AuthenticatedShare> {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([5; 32]);
+        Shamir::new(threshold)
+            .unwrap()
+            .split_authenticated(b"authenticated secret", shares, &mut rng)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_gf128_mul() {
+        let x = 2u128;
+        assert_eq!(gf128_mul(x, 1), x);
+        assert_eq!(gf128_mul(1 << 127, x), 0x87);
+        for (a, b) in [
+            (0x1234u128, 0xfedcu128),
+            (u128::MAX, 0x87),
+            (1 << 100, 1 << 90),
+        ] {
+            assert_eq!(gf128_mul(a, b), gf128_mul(b, a));
+            assert_eq!(gf128_mul(a, b ^ 3), gf128_mul(a, b) ^ gf128_mul(a, 3));
+        }
+    }
+
+    #[test]
+    fn test_honest_shares_pass() {
+        let shares = split(3, 5);
+        for verifier in &shares {
+            for other in &shares {
+                if verifier.share.x != other.share.x {
+                    assert_eq!(verifier.verify(other), Ok(()));
+                }
+            }
+        }
+
+        let shamir = Shamir::new(3).unwrap();
+        let (secret, report) = shamir.recover_authenticated(&shares[1..]).unwrap();
+        assert_eq!(secret, b"authenticated secret");
+        assert!(report.is_authentic());
+        assert_eq!(report.accepted, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_cheater_is_identified() {
+        let mut shares = split(2, 4);
+        shares[1].share.y[3] = shares[1].share.y[3] + GF256(0x40);
+
+        assert_eq!(
+            shares[0].verify(&shares[1]),
+            Err(ShamirError::AuthenticationFailed(2))
+        );
+
+        // Share 2 comes first, so plain recovery would use it and fail
+        let shamir = Shamir::new(2).unwrap();
+        let pool = [&shares[1], &shares[0], &shares[3]];
+        assert_eq!(
+            shamir.recover(pool.map(|share| &share.share)),
+            Err(ShamirError::ChecksumMismatch)
+        );
+
+        let (secret, report) = shamir.recover_authenticated(pool).unwrap();
+        assert_eq!(secret, b"authenticated secret");
+        assert_eq!(report.rejected, vec![2]);
+        assert_eq!(report.accepted, vec![1, 4]);
+        assert_eq!(report.accusations, vec![(1, 2), (4, 2)]);
+    }
+
+    #[test]
+    fn test_false_accusations_are_outvoted() {
+        let mut shares = split(2, 4);
+        // The holder of share 4 corrupts its keys to accuse everyone else
+        for (_, key) in &mut shares[3].auth.keys {
+            key.c ^= 1;
+        }
+
+        let report = check(&shares);
+        assert!(report.is_authentic());
+        assert_eq!(report.accusations, vec![(4, 1), (4, 2), (4, 3)]);
+    }
+
+    #[test]
+    fn test_moved_share_is_rejected() {
+        let mut shares = split(2, 3);
+        // Relabelling a share as another holder's does not carry its tags over
+        shares[2].share.x = GF256(9);
+        assert_eq!(
+            shares[0].verify(&shares[2]),
+            Err(ShamirError::AuthenticationFailed(9))
+        );
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let shares = split(2, 3);
+        for share in &shares {
+            let bytes = share.auth.to_bytes();
+            assert_eq!(bytes.len(), 3 + 2 * 17 + 2 * 33);
+            assert_eq!(Authentication::from_bytes(&bytes).unwrap(), share.auth);
+
+            assert_eq!(
+                Authentication::from_bytes(&bytes[..bytes.len() - 1]),
+                Err(ShamirError::InvalidEncoding)
+            );
+            let mut trailing = bytes.clone();
+            trailing.push(0);
+            assert_eq!(
+                Authentication::from_bytes(&trailing),
+                Err(ShamirError::InvalidEncoding)
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_errors() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([5; 32]);
+        let shamir = Shamir::new(3).unwrap();
+        assert_eq!(
+            shamir.split_authenticated(b"secret", 2, &mut rng),
+            Err(ShamirError::InvalidThreshold)
+        );
+        assert_eq!(
+            shamir.split_authenticated(b"", 3, &mut rng),
+            Err(ShamirError::EmptyInput)
+        );
+    }
+}
@@ -0,0 +1,327 @@
+//! Deterministic splitting from a passphrase and salt.
+//!
+//! Splitting the same secret with the same passphrase, salt, threshold and
+//! [`KdfParams`] always produces the same shares, so a lost share can be
+//! re-created later. The construction only relies on Argon2id and SHA-256 and
+//! is specified here so it can be reimplemented independently:
+//!
+//! ```text
+//! K      = Argon2id(passphrase, salt, m = memory_kib, t = iterations,
+//!                   p = parallelism, version 0x13, 32-byte output)
+//! S      = SHA-256("fractus-shamir/passphrase-split/v1" || K || threshold
+//!                  || u64be(len(secret)) || secret)
+//! stream = SHA-256(S || u64be(0)) || SHA-256(S || u64be(1)) || ...
+//! ```
+//!
+//! `threshold` is a single byte. The polynomials are those of
+//! [`Shamir::split_with_rng`]: the constant terms are the secret followed by its
+//! big-endian CRC32, L bytes in all. The coefficient of x^p for byte i, for
+//! p = 1..threshold-1 and i = 0..L-1 with p varying slowest, is the next
+//! non-zero byte of `stream`. The share with x-coordinate x (1 to 255) holds
+//! the value of each of the L polynomials at x in GF(2^8) modulo
+//! x^8 + x^4 + x^3 + x + 1.
+//!
+//! Mixing the secret into `S` keeps two different secrets split under the same
+//! passphrase from sharing coefficients, which would reveal how they differ.
+use argon2::{Algorithm, Argon2, Params, Version};
+use sha2::{Digest, Sha256};
+
+use super::gf256::GF256;
+use super::{CHECKSUM_LEN, Result, Shamir, ShamirError, Share, poly};
+
+/// Domain separation tag, versioned so the construction can change later.
+const DOMAIN: &[u8] = b"fractus-shamir/passphrase-split/v1";
+
+/// Argon2id cost parameters for [`Shamir::split_from_passphrase`].
+///
+/// The same parameters are needed to re-create a share set, so prefer the
+/// defaults unless they are recorded alongside the salt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KdfParams {
+    /// Memory cost in KiB
+    pub memory_kib: u32,
+    /// Number of passes over the memory
+    pub iterations: u32,
+    /// Degree of parallelism
+    pub parallelism: u32,
+}
+
+impl KdfParams {
+    /// Minimum salt length accepted by Argon2.
+    pub const MIN_SALT_LEN: usize = 8;
+}
+
+impl Default for KdfParams {
+    /// 64 MiB, 3 passes, 1 lane.
+    fn default() -> Self {
+        Self {
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+impl Shamir {
+    /// Splits a secret with coefficients derived from a passphrase and salt.
+    ///
+    /// The result depends only on the inputs and is stable across versions and
+    /// platforms; see the [module documentation](self) for the exact
+    /// construction. Anyone who knows the passphrase, the salt and the secret
+    /// can reproduce every share, so the passphrase must be at least as well
+    /// protected as the shares.
+    ///
+    /// # Errors
+    /// * `ShamirError::EmptyInput` - The secret or the passphrase is empty
+    /// * `ShamirError::InvalidSalt` - The salt is shorter than
+    ///   [`KdfParams::MIN_SALT_LEN`] bytes
+    /// * `ShamirError::InvalidKdfParams` - Argon2 rejected `params`
+    ///
+    /// # Examples
+    /// ```
+    /// use fractus_shamir::Shamir;
+    /// use fractus_shamir::passphrase::KdfParams;
+    ///
+    /// let shamir = Shamir::new(2).unwrap();
+    /// let params = KdfParams { memory_kib: 64, iterations: 1, parallelism: 1 };
+    /// let split = |shamir: &Shamir| -> Vec<_> {
+    ///     shamir
+    ///         .split_from_passphrase(b"secret", b"correct horse", b"vault-2024", &params)
+    ///         .unwrap()
+    ///         .take(3)
+    ///         .collect()
+    /// };
+    ///
+    /// // A lost share can be re-created later
+    /// assert_eq!(split(&shamir), split(&shamir));
+    /// ```
+    pub fn split_from_passphrase(
+        &self,
+        secret: &[u8],
+        passphrase: &[u8],
+        salt: &[u8],
+        params: &KdfParams,
+    ) -> Result<impl Iterator<Item = Share> + use<>> {
+        if secret.is_empty() || passphrase.is_empty() {
+            return Err(ShamirError::EmptyInput);
+        }
+        if salt.len() < KdfParams::MIN_SALT_LEN {
+            return Err(ShamirError::InvalidSalt {
+                min: KdfParams::MIN_SALT_LEN,
+            });
+        }
+
+        let argon2_params = Params::new(
+            params.memory_kib,
+            params.iterations,
+            params.parallelism,
+            Some(32),
+        )
+        .map_err(|_| ShamirError::InvalidKdfParams)?;
+        let mut key = [0u8; 32];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params)
+            .hash_password_into(passphrase, salt, &mut key)
+            .map_err(|_| ShamirError::InvalidKdfParams)?;
+
+        let seed: [u8; 32] = Sha256::new()
+            .chain_update(DOMAIN)
+            .chain_update(key)
+            .chain_update([self.threshold])
+            .chain_update((secret.len() as u64).to_be_bytes())
+            .chain_update(secret)
+            .finalize()
+            .into();
+        key.fill(0);
+
+        let checksum = crc32fast::hash(secret).to_be_bytes();
+        let mut stream = Stream::new(seed);
+        let coefficients =
+            poly::Coefficients::from_rows(&[secret, &checksum], self.threshold, |row| {
+                row.fill_with(|| stream.next_nonzero())
+            });
+        debug_assert_eq!(coefficients.len(), secret.len() + CHECKSUM_LEN);
+
+        Ok((1..=u8::MAX).map(GF256).map(move |x| {
+            let mut y = vec![0u8; coefficients.len()];
+            coefficients.evaluate_into(x, &mut y);
+            Share::new(x, y.into_iter().map(GF256).collect())
+        }))
+    }
+}
+
+/// The SHA-256 counter-mode byte stream from the module documentation.
+struct Stream {
+    seed: [u8; 32],
+    counter: u64,
+    block: [u8; 32],
+    position: usize,
+}
+
+impl Stream {
+    fn new(seed: [u8; 32]) -> Self {
+        Self {
+            seed,
+            counter: 0,
+            block: [0; 32],
+            position: 32,
+        }
+    }
+
+    fn next_nonzero(&mut self) -> u8 {
+        loop {
+            if self.position == self.block.len() {
+                self.block = Sha256::new()
+                    .chain_update(self.seed)
+                    .chain_update(self.counter.to_be_bytes())
+                    .finalize()
+                    .into();
+                self.counter += 1;
+                self.position = 0;
+            }
+
+            let byte = self.block[self.position];
+            self.position += 1;
+            if byte != 0 {
+                return byte;
+            }
+        }
+    }
+}
+
+impl Drop for Stream {
+    fn drop(&mut self) {
+        self.seed.fill(0);
+        self.block.fill(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAST: KdfParams = KdfParams {
+        memory_kib: 64,
+        iterations: 1,
+        parallelism: 1,
+    };
+
+    fn split(secret: &[u8], passphrase: &[u8], salt: &[u8], threshold: u8) -> Vec<Share> {
+        Shamir::new(threshold)
+            .unwrap()
+            .split_from_passphrase(secret, passphrase, salt, &FAST)
+            .unwrap()
+            .take(5)
+            .collect()
+    }
+
+    #[test]
+    fn test_deterministic_and_recoverable() {
+        let shares = split(b"seed phrase", b"passphrase", b"saltsalt", 3);
+        assert_eq!(shares, split(b"seed phrase", b"passphrase", b"saltsalt", 3));
+
+        let shamir = Shamir::new(3).unwrap();
+        assert_eq!(shamir.recover(&shares[2..]).unwrap(), b"seed phrase");
+
+        // Every coefficient is non-zero, so the polynomials have full degree
+        assert_eq!(Shamir::recover_auto(&shares).unwrap().threshold, 3);
+    }
+
+    #[test]
+    fn test_every_input_changes_the_shares() {
+        let base = split(b"seed phrase", b"passphrase", b"saltsalt", 3);
+        assert_ne!(base, split(b"seed phrase", b"passphrasf", b"saltsalt", 3));
+        assert_ne!(base, split(b"seed phrase", b"passphrase", b"saltsalu", 3));
+        assert_ne!(base, split(b"seed phrase", b"passphrase", b"saltsalt", 2));
+
+        let params = KdfParams {
+            iterations: 2,
+            ..FAST
+        };
+        let other_params: Vec<Share> = Shamir::new(3)
+            .unwrap()
+            .split_from_passphrase(b"seed phrase", b"passphrase", b"saltsalt", &params)
+            .unwrap()
+            .take(5)
+            .collect();
+        assert_ne!(base, other_params);
+    }
+
+    #[test]
+    fn test_different_secrets_do_not_share_coefficients() {
+        let a = split(b"attack at dawn", b"passphrase", b"saltsalt", 2);
+        let b = split(b"attack at dusk", b"passphrase", b"saltsalt", 2);
+
+        let share_difference: Vec<u8> = a[0]
+            .y
+            .iter()
+            .zip(&b[0].y)
+            .map(|(a, b)| (*a - *b).0)
+            .collect();
+        let secret_difference: Vec<u8> = b"attack at dawn"
+            .iter()
+            .zip(b"attack at dusk")
+            .map(|(a, b)| a ^ b)
+            .collect();
+        assert_ne!(share_difference[..14], secret_difference);
+    }
+
+    #[test]
+    fn test_specification_is_stable() {
+        // Pins the construction described in the module documentation with the
+        // default parameters; changing it would strand existing share sets. The
+        // expected bytes were also reproduced from the specification with an
+        // independent SHA-256/GF(256) implementation, given Argon2id's output
+        let shares: Vec<Share> = Shamir::new(2)
+            .unwrap()
+            .split_from_passphrase(
+                b"fractus",
+                b"correct horse battery staple",
+                b"fractus-test-salt",
+                &KdfParams::default(),
+            )
+            .unwrap()
+            .take(2)
+            .collect();
+
+        let bytes: Vec<Vec<u8>> = shares.iter().map(Share::to_bytes).collect();
+        assert_eq!(
+            bytes[0],
+            [1, 163, 179, 148, 18, 31, 217, 172, 41, 126, 92, 62]
+        );
+        assert_eq!(
+            bytes[1],
+            [2, 247, 235, 144, 129, 162, 54, 214, 200, 64, 27, 33]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let shamir = Shamir::new(2).unwrap();
+        let err = |secret: &[u8], passphrase: &[u8], salt: &[u8], params: &KdfParams| {
+            shamir
+                .split_from_passphrase(secret, passphrase, salt, params)
+                .err()
+        };
+
+        assert_eq!(
+            err(b"", b"pass", b"saltsalt", &FAST),
+            Some(ShamirError::EmptyInput)
+        );
+        assert_eq!(
+            err(b"secret", b"", b"saltsalt", &FAST),
+            Some(ShamirError::EmptyInput)
+        );
+        assert_eq!(
+            err(b"secret", b"pass", b"short", &FAST),
+            Some(ShamirError::InvalidSalt { min: 8 })
+        );
+        let params = KdfParams {
+            parallelism: 0,
+            ..FAST
+        };
+        assert_eq!(
+            err(b"secret", b"pass", b"saltsalt", &params),
+            Some(ShamirError::InvalidKdfParams)
+        );
+    }
+}
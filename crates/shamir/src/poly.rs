@@ -2,12 +2,28 @@
 //!
 //! This module provides functions for generating random polynomials,
 //! evaluating them at different points, and performing Lagrange interpolation
-//! to recover the original secret.
+//! to recover the original secret. [`Polynomial`] offers general polynomial
+//! arithmetic over any [`Field`](crate::field::Field) for protocols built on
+//! top of plain sharing.
 
+use core::borrow::Borrow;
+use core::ops::Range;
+
+use rand::RngCore;
 use rand::distributions::{Distribution, Uniform};
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-use super::gf256::GF256;
+use super::field::Field;
+use super::gf256::{GF256, as_bytes, mul_add_slice};
 use super::share::Share;
+use super::{Result, ShamirError};
+
+mod polynomial;
+
+pub use polynomial::Polynomial;
 
 /// Performs Lagrange interpolation to recover the secret from shares.
 ///
@@ -15,12 +31,19 @@ use super::share::Share;
 /// to find the polynomial that passes through all the given points, then evaluates
 /// it at x=0 to recover the original secret.
 ///
+/// The Lagrange weights only depend on the x-coordinates, so they are computed
+/// once up front (see [`lagrange_coefficients_at_zero`]) and every byte position
+/// reduces to a dot product of the weights with the shares' y-values.
+///
 /// # Arguments
-/// * `shares` - A slice of shares to use for interpolation
+/// * `shares` - A slice of shares (or share references) to use for interpolation
 ///
 /// # Returns
 /// A vector of bytes representing the recovered secret with checksum
 ///
+/// # Panics
+/// Panics if the shares' y-vectors have different lengths.
+///
 /// # Examples
 /// ```
 /// use fractus_shamir::{Share, gf256::GF256};
@@ -32,53 +55,156 @@ use super::share::Share;
 /// ];
 /// let result = interpolate(&shares);
 /// ```
-pub fn interpolate(shares: &[Share]) -> Vec<u8> {
+pub fn interpolate<S: Borrow<Share> + Sync>(shares: &[S]) -> Vec<u8> {
+    interpolate_at(shares, GF256::ZERO)
+}
+
+/// Computes the Lagrange basis polynomials of the given x-coordinates evaluated at x=0.
+///
+/// For points x_0..x_{k-1} the weight of point j is:
+/// w_j = Π(i=0 to k-1, i≠j) (0 - x_i) / (x_j - x_i) = Π(i≠j) x_i / (x_j - x_i)
+///
+/// so that the value at zero of any polynomial of degree < k through points
+/// (x_j, y_j) is Σ w_j * y_j. This is [`lagrange_coefficients`] at zero.
+///
+/// # Arguments
+/// * `xs` - Distinct x-coordinates of the points
+///
+/// # Returns
+/// One weight per x-coordinate, in the same order
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::gf256::GF256;
+/// use fractus_shamir::poly::lagrange_coefficients_at_zero;
+///
+/// let weights = lagrange_coefficients_at_zero(&[GF256(1), GF256(2)]);
+/// // f(x) = x + 5: f(1) = 4, f(2) = 7, f(0) = 5
+/// assert_eq!(weights[0] * GF256(4) + weights[1] * GF256(7), GF256(5));
+/// ```
+pub fn lagrange_coefficients_at_zero<F: Field>(xs: &[F]) -> Vec<F> {
+    lagrange_coefficients(xs, F::ZERO)
+}
+
+/// Computes the Lagrange basis polynomials of the given x-coordinates evaluated at `at`.
+///
+/// The weight of point j is w_j = Π(i≠j) (at - x_i) / (x_j - x_i), so that the
+/// value at `at` of any polynomial of degree < k through points (x_j, y_j) is
+/// Σ w_j * y_j. The numerator and denominator products are accumulated
+/// separately, which costs a single field inversion per weight. Any
+/// [`Field`] works, so foreign GF(2^8) fields share this code.
+///
+/// # Arguments
+/// * `xs` - Distinct x-coordinates of the points
+/// * `at` - The point at which the interpolating polynomial is evaluated
+///
+/// # Returns
+/// One weight per x-coordinate, in the same order
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::gf256::GF256;
+/// use fractus_shamir::poly::lagrange_coefficients;
+///
+/// let weights = lagrange_coefficients(&[GF256(1), GF256(2)], GF256(3));
+/// // f(x) = x + 5: f(1) = 4, f(2) = 7, f(3) = 6
+/// assert_eq!(weights[0] * GF256(4) + weights[1] * GF256(7), GF256(6));
+/// ```
+pub fn lagrange_coefficients<F: Field>(xs: &[F], at: F) -> Vec<F> {
+    xs.iter()
+        .enumerate()
+        .map(|(j, &x_j)| {
+            let (numerator, denominator) = xs
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != j)
+                .fold((F::ONE, F::ONE), |(num, den), (_, &x_i)| {
+                    (num * (at - x_i), den * (x_j - x_i))
+                });
+            numerator / denominator
+        })
+        .collect()
+}
+
+/// Evaluates the polynomial through the given shares at `at`, byte by byte.
+///
+/// This generalizes [`interpolate`], which evaluates at zero, and is what lets a
+/// surplus share be checked against the polynomial defined by the others.
+///
+/// # Panics
+/// Panics if the shares' y-vectors have different lengths.
+pub fn interpolate_at<S: Borrow<Share> + Sync>(shares: &[S], at: GF256) -> Vec<u8> {
     if shares.is_empty() {
         return Vec::new();
     }
 
-    let secret_len = shares[0].y.len();
-    let mut result = Vec::with_capacity(secret_len);
+    let xs: Vec<GF256> = shares.iter().map(|share| share.borrow().x).collect();
+    let weights = lagrange_coefficients(&xs, at);
 
-    // For each byte position in the secret
-    for byte_index in 0..secret_len {
-        let recovered_byte = lagrange_interpolate_at_zero(shares, byte_index);
-        result.push(recovered_byte.0);
-    }
+    let secret_len = shares[0].borrow().y.len();
+    let mut result = vec![0u8; secret_len];
+
+    // Σ w_j * y_j, accumulated one share at a time so each pass is a straight
+    // walk over a single y-vector
+    for_each_chunk(&mut result, |range, out| {
+        for (share, &weight) in shares.iter().zip(&weights) {
+            mul_add_slice(out, &as_bytes(&share.borrow().y)[range.clone()], weight);
+        }
+    });
 
     result
 }
 
-/// Performs Lagrange interpolation for a specific byte position and evaluates at x=0.
+/// Performs Lagrange interpolation at zero in another GF(2^8) field.
 ///
-/// This is the core of the secret recovery process. For each byte position,
-/// we have a polynomial where the shares represent points on that polynomial.
-/// We use Lagrange interpolation to find the value at x=0, which is the original secret byte.
+/// Shares produced by implementations that use a different reducing polynomial
+/// (see [`Gf2p8`](crate::gf256::Gf2p8)) must be interpolated with that field's
+/// arithmetic. The bytes of each share are reinterpreted as elements of `F`;
+/// this takes the portable scalar path rather than the GF256 kernels.
 ///
-/// The Lagrange interpolation formula is:
-/// f(0) = Σ(j=0 to k-1) y_j * Π(i=0 to k-1, i≠j) (0 - x_i) / (x_j - x_i)
+/// # Panics
+/// Panics if the shares' y-vectors have different lengths or if two shares
+/// have the same x-coordinate.
 ///
-/// Since we're evaluating at x=0, this simplifies to:
-/// f(0) = Σ(j=0 to k-1) y_j * Π(i=0 to k-1, i≠j) (-x_i) / (x_j - x_i)
-/// f(0) = Σ(j=0 to k-1) y_j * Π(i=0 to k-1, i≠j) x_i / (x_j - x_i)
-fn lagrange_interpolate_at_zero(shares: &[Share], byte_index: usize) -> GF256 {
-    shares
+/// # Examples
+/// ```
+/// use fractus_shamir::gf256::{GF256, Gf2p8Gfshare};
+/// use fractus_shamir::poly::interpolate_in;
+/// use fractus_shamir::Share;
+///
+/// // f(x) = 0x80 * x + 7 over GF(2^8)/0x11d
+/// let shares = vec![
+///     Share::new(GF256(1), vec![GF256(0x87)]),
+///     Share::new(GF256(2), vec![GF256(0x1d ^ 7)]),
+/// ];
+/// assert_eq!(interpolate_in::<Gf2p8Gfshare, _>(&shares), vec![7]);
+/// ```
+pub fn interpolate_in<F, S>(shares: &[S]) -> Vec<u8>
+where
+    F: Field + From<u8> + Into<u8>,
+    S: Borrow<Share>,
+{
+    if shares.is_empty() {
+        return Vec::new();
+    }
+
+    let xs: Vec<F> = shares
         .iter()
-        .map(|share_j| {
-            // Calculate the Lagrange basis polynomial for share_j evaluated at x=0
-            let basis = shares
+        .map(|share| F::from(share.borrow().x.0))
+        .collect();
+    let weights = lagrange_coefficients_at_zero(&xs);
+
+    let secret_len = shares[0].borrow().y.len();
+    (0..secret_len)
+        .map(|i| {
+            shares
                 .iter()
-                .filter(|share_i| share_i.x != share_j.x)
-                .map(|share_i| {
-                    // For Lagrange basis: (0 - x_i) / (x_j - x_i) = x_i / (x_j - x_i)
-                    share_i.x / (share_j.x - share_i.x)
-                })
-                .product::<GF256>();
-
-            // Multiply by the y-value for this share and byte position
-            basis * share_j.y[byte_index]
+                .zip(&weights)
+                .map(|(share, &weight)| weight * F::from(share.borrow().y[i].0))
+                .sum::<F>()
+                .into()
         })
-        .sum::<GF256>()
+        .collect()
 }
 
 /// Generates a random polynomial of degree `threshold - 1` with the given constant term.
@@ -130,6 +256,218 @@ pub fn random_polynomial<R: rand::Rng>(
     coefficients
 }
 
+/// The random polynomials for every byte of a secret, stored column-major.
+///
+/// Instead of one heap-allocated polynomial per secret byte, all coefficients
+/// live in a single buffer laid out one degree after another:
+/// `[a_0 for every byte][a_1 for every byte]...[a_{k-1} for every byte]`.
+/// Row 0 holds the secret itself. Evaluating every byte at some `x` then
+/// becomes `k` passes of "multiply a row by a constant and add", each a linear
+/// walk over contiguous memory.
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::gf256::GF256;
+/// use fractus_shamir::poly::Coefficients;
+/// use rand_chacha::rand_core::SeedableRng;
+///
+/// let mut rng = rand_chacha::ChaCha8Rng::from_seed([0; 32]);
+/// let coefficients = Coefficients::random(&[10, 20], 3, &mut rng);
+///
+/// let mut y = [0u8; 2];
+/// coefficients.evaluate_into(GF256(0), &mut y);
+/// assert_eq!(y, [10, 20]); // the constant terms are the secret
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coefficients {
+    threshold: usize,
+    len: usize,
+    data: Vec<u8>,
+}
+
+impl Coefficients {
+    /// Generates random polynomials of degree `threshold - 1`, one per secret byte.
+    ///
+    /// The secret is divided into chunks of [`CHUNK_LEN`] bytes. For each chunk,
+    /// in order, a 32-byte seed is read from `rng` and expanded with ChaCha20
+    /// into that chunk's coefficients: the chunk's slice of the `x^1` row first,
+    /// then `x^2` and so on, with zero bytes replaced by further output so every
+    /// coefficient is non-zero. Chunks are therefore independent of each other
+    /// and can be generated in parallel, while a seeded `rng` still yields the
+    /// same polynomials regardless of how many threads are used.
+    ///
+    /// The polynomials produced for a given seeded `rng` are not a stable
+    /// interface: this chunked derivation already differs from the one of
+    /// earlier versions, which read every coefficient from `rng` directly.
+    ///
+    /// # Arguments
+    /// * `secret` - The constant terms of the polynomials
+    /// * `threshold` - The minimum number of shares needed to recover the secret
+    /// * `rng` - Random number generator for generating coefficients
+    pub fn random<R: rand::Rng>(secret: &[u8], threshold: u8, rng: &mut R) -> Self {
+        Self::random_parts(&[secret], threshold, rng)
+    }
+
+    /// Like [`Coefficients::random`], but takes the secret as consecutive parts.
+    ///
+    /// This lets callers append trailers such as a checksum without first
+    /// concatenating them into a temporary buffer.
+    pub fn random_parts<R: rand::Rng>(parts: &[&[u8]], threshold: u8, rng: &mut R) -> Self {
+        let mut coefficients = Self::with_constant_terms(parts, threshold);
+        let len = coefficients.len;
+
+        // Seeds are always drawn sequentially from the caller's RNG
+        let seeds: Vec<[u8; 32]> = (0..len.div_ceil(CHUNK_LEN))
+            .map(|_| {
+                let mut seed = [0u8; 32];
+                rng.fill_bytes(&mut seed);
+                seed
+            })
+            .collect();
+
+        // Regroup the random rows so each chunk owns its slice of every row
+        let mut chunks: Vec<Vec<&mut [u8]>> = seeds.iter().map(|_| Vec::new()).collect();
+        if len > 0 {
+            for row in coefficients.data[len..].chunks_mut(len) {
+                for (chunk, part) in chunks.iter_mut().zip(row.chunks_mut(CHUNK_LEN)) {
+                    chunk.push(part);
+                }
+            }
+        }
+
+        let fill_chunk = |(rows, seed): (Vec<&mut [u8]>, [u8; 32])| {
+            let mut chunk_rng = ChaCha20Rng::from_seed(seed);
+            for row in rows {
+                fill_nonzero(&mut chunk_rng, row);
+            }
+        };
+
+        #[cfg(feature = "parallel")]
+        chunks.into_par_iter().zip(seeds).for_each(fill_chunk);
+        #[cfg(not(feature = "parallel"))]
+        chunks.into_iter().zip(seeds).for_each(fill_chunk);
+
+        coefficients
+    }
+
+    /// Like [`Coefficients::random_parts`], but with the random rows written by
+    /// `fill_row` instead of drawn from an RNG.
+    ///
+    /// `fill_row` is called once per power from 1 to `threshold - 1`, in
+    /// order, with that power's row for every byte of the secret. It must
+    /// write non-zero bytes only.
+    #[cfg(feature = "passphrase")]
+    pub(crate) fn from_rows<F: FnMut(&mut [u8])>(
+        parts: &[&[u8]],
+        threshold: u8,
+        mut fill_row: F,
+    ) -> Self {
+        let mut coefficients = Self::with_constant_terms(parts, threshold);
+        let len = coefficients.len;
+        if len > 0 {
+            coefficients.data[len..]
+                .chunks_mut(len)
+                .for_each(&mut fill_row);
+        }
+        coefficients
+    }
+
+    /// Lays out the parts as the constant terms, leaving the rows of the
+    /// higher powers zeroed for the caller to fill.
+    fn with_constant_terms(parts: &[&[u8]], threshold: u8) -> Self {
+        let len: usize = parts.iter().map(|part| part.len()).sum();
+        let degree = threshold.max(1) as usize;
+        let mut data = vec![0u8; len * degree];
+
+        let mut offset = 0;
+        for part in parts {
+            data[offset..offset + part.len()].copy_from_slice(part);
+            offset += part.len();
+        }
+
+        Self {
+            threshold: degree,
+            len,
+            data,
+        }
+    }
+
+    /// Returns the number of polynomials (bytes of the shared secret).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no polynomials.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of coefficients per polynomial (the threshold).
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the coefficients of `x^power` for every byte.
+    ///
+    /// # Panics
+    /// Panics if `power` is not less than the threshold.
+    pub fn row(&self, power: usize) -> &[u8] {
+        &self.data[power * self.len..(power + 1) * self.len]
+    }
+
+    /// Evaluates every polynomial at `x`, writing one byte per polynomial into `out`.
+    ///
+    /// # Panics
+    /// Panics if `out` is not exactly [`Coefficients::len`] bytes long.
+    pub fn evaluate_into(&self, x: GF256, out: &mut [u8]) {
+        assert_eq!(out.len(), self.len, "Output buffer has the wrong length");
+
+        for_each_chunk(out, |range, out| {
+            out.copy_from_slice(&self.row(0)[range.clone()]);
+
+            let mut x_power = GF256::ONE;
+            for power in 1..self.threshold() {
+                x_power = x_power * x;
+                mul_add_slice(out, &self.row(power)[range.clone()], x_power);
+            }
+        });
+    }
+}
+
+/// Number of secret bytes whose coefficients come from one derived random stream.
+///
+/// This fixes how a seeded RNG maps to shares, so changing it changes the
+/// output of every seeded split.
+pub const CHUNK_LEN: usize = 64 * 1024;
+
+/// Fills `buf` with random non-zero bytes.
+fn fill_nonzero<R: RngCore>(rng: &mut R, buf: &mut [u8]) {
+    rng.fill_bytes(buf);
+    for byte in buf.iter_mut() {
+        while *byte == 0 {
+            *byte = rng.next_u32() as u8;
+        }
+    }
+}
+
+/// Runs `f` on consecutive [`CHUNK_LEN`]-sized pieces of `out`, passing each
+/// piece along with its range in `out`. With the `parallel` feature the pieces
+/// are processed on the rayon thread pool.
+fn for_each_chunk<F>(out: &mut [u8], f: F)
+where
+    F: Fn(Range<usize>, &mut [u8]) + Send + Sync,
+{
+    let run = |(index, chunk): (usize, &mut [u8])| {
+        let start = index * CHUNK_LEN;
+        f(start..start + chunk.len(), chunk)
+    };
+
+    #[cfg(feature = "parallel")]
+    out.par_chunks_mut(CHUNK_LEN).enumerate().for_each(run);
+    #[cfg(not(feature = "parallel"))]
+    out.chunks_mut(CHUNK_LEN).enumerate().for_each(run);
+}
+
 /// Returns an iterator that evaluates polynomials at successive x-values.
 ///
 /// This function creates an iterator that evaluates each polynomial in `polys`
@@ -210,32 +548,33 @@ pub fn evaluate_polynomial(coefficients: &[GF256], x: GF256) -> GF256 {
 /// required for proper secret sharing operation.
 ///
 /// # Arguments
-/// * `polys` - The polynomials to validate
+/// * `polys` - The polynomials to validate, coefficients in descending order
 /// * `expected_threshold` - The expected threshold (degree + 1)
 ///
-/// # Returns
-/// `Ok(())` if valid, or an error message if invalid
-pub fn validate_polynomials(polys: &[Vec<GF256>], expected_threshold: u8) -> Result<(), String> {
+/// # Errors
+/// * `ShamirError::EmptyInput` - No polynomials were given
+/// * `ShamirError::PolynomialLengthMismatch` - A polynomial has the wrong number of coefficients
+/// * `ShamirError::ZeroLeadingCoefficient` - A polynomial's degree is lower than it appears
+pub fn validate_polynomials(polys: &[Vec<GF256>], expected_threshold: u8) -> Result<()> {
     if polys.is_empty() {
-        return Err("No polynomials provided".to_string());
+        return Err(ShamirError::EmptyInput);
     }
 
-    let expected_degree = expected_threshold as usize;
+    let expected = expected_threshold as usize;
 
-    for (i, poly) in polys.iter().enumerate() {
-        if poly.len() != expected_degree {
-            return Err(format!(
-                "Polynomial {} has degree {} but expected degree {}",
-                i,
-                poly.len(),
-                expected_degree
-            ));
+    for (index, poly) in polys.iter().enumerate() {
+        if poly.len() != expected {
+            return Err(ShamirError::PolynomialLengthMismatch {
+                index,
+                expected,
+                actual: poly.len(),
+            });
         }
 
         // Check that the polynomial actually has the expected degree
         // (highest coefficient should be non-zero)
         if poly.len() > 1 && poly[0].is_zero() {
-            return Err(format!("Polynomial {} has zero leading coefficient", i));
+            return Err(ShamirError::ZeroLeadingCoefficient(index));
         }
     }
 
@@ -309,10 +648,65 @@ mod tests {
         assert_eq!(result, vec![5]);
     }
 
+    #[test]
+    fn test_lagrange_coefficients_match_basis_products() {
+        let xs = [GF256(3), GF256(7), GF256(42), GF256(200)];
+        let weights = lagrange_coefficients_at_zero(&xs);
+
+        for (j, &x_j) in xs.iter().enumerate() {
+            let expected: GF256 = xs
+                .iter()
+                .filter(|&&x_i| x_i != x_j)
+                .map(|&x_i| x_i / (x_j - x_i))
+                .product();
+            assert_eq!(weights[j], expected);
+        }
+
+        // The weights of a constant polynomial must sum to one
+        assert_eq!(weights.iter().copied().sum::<GF256>(), GF256::ONE);
+    }
+
+    #[test]
+    fn test_interpolation_at_other_points() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([8; 32]);
+        let coefficients = Coefficients::random(&[9, 8, 7], 3, &mut rng);
+        let shares: Vec<Share> = (1..=6)
+            .map(|x| {
+                let mut y = [0u8; 3];
+                coefficients.evaluate_into(GF256(x), &mut y);
+                Share::new(GF256(x), y.map(GF256).to_vec())
+            })
+            .collect();
+
+        // Any three shares determine the values of the others
+        for share in &shares[3..] {
+            assert_eq!(
+                interpolate_at(&shares[..3], share.x).as_slice(),
+                as_bytes(&share.y)
+            );
+        }
+        assert_eq!(interpolate_at(&shares[2..5], GF256::ZERO), vec![9, 8, 7]);
+    }
+
+    #[test]
+    fn test_interpolation_from_references() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([7; 32]);
+        let polys: Vec<_> = [GF256(1), GF256(2), GF256(3)]
+            .iter()
+            .map(|&byte| random_polynomial(byte, 4, &mut rng))
+            .collect();
+
+        let shares: Vec<_> = evaluator(polys).skip(10).take(4).collect();
+        let share_refs: Vec<&Share> = shares.iter().collect();
+
+        assert_eq!(interpolate(&share_refs), vec![1, 2, 3]);
+        assert_eq!(interpolate(&share_refs), interpolate(&shares));
+    }
+
     #[test]
     fn test_evaluator_consistency() {
         let mut rng = rand_chacha::ChaCha8Rng::from_seed([0; 32]);
-        let secret_bytes = vec![GF256(10), GF256(20)];
+        let secret_bytes = [GF256(10), GF256(20)];
         let threshold = 3;
 
         let polys: Vec<_> = secret_bytes
@@ -330,6 +724,78 @@ mod tests {
         assert_eq!(x_coords.len(), shares.len());
     }
 
+    #[test]
+    fn test_coefficients_match_per_byte_polynomials() {
+        let secret = [100u8, 200, 50, 0, 255];
+        let threshold = 4;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([9; 32]);
+        let coefficients = Coefficients::random(&secret, threshold, &mut rng);
+        assert_eq!(coefficients.len(), secret.len());
+        assert_eq!(coefficients.threshold(), threshold as usize);
+        assert_eq!(coefficients.row(0), &secret);
+
+        // Per-byte polynomials in descending order of degree
+        let polys: Vec<Vec<GF256>> = (0..secret.len())
+            .map(|i| {
+                (0..threshold as usize)
+                    .rev()
+                    .map(|power| GF256(coefficients.row(power)[i]))
+                    .collect()
+            })
+            .collect();
+
+        let mut y = [0u8; 5];
+        for share in evaluator(polys).take(20) {
+            coefficients.evaluate_into(share.x, &mut y);
+            assert_eq!(y.map(GF256).as_slice(), share.y());
+        }
+    }
+
+    #[test]
+    fn test_coefficients_are_non_zero_across_chunks() {
+        let secret = vec![0u8; 2 * CHUNK_LEN + 17];
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([11; 32]);
+        let coefficients = Coefficients::random(&secret, 3, &mut rng);
+
+        for power in 1..3 {
+            assert!(coefficients.row(power).iter().all(|&c| c != 0));
+        }
+
+        // Each chunk is driven by its own stream
+        let row = coefficients.row(1);
+        assert_ne!(row[..64], row[CHUNK_LEN..CHUNK_LEN + 64]);
+    }
+
+    #[test]
+    fn test_interpolation_across_chunks() {
+        let secret: Vec<u8> = (0..CHUNK_LEN + 1000).map(|i| (i * 13) as u8).collect();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([12; 32]);
+        let coefficients = Coefficients::random(&secret, 3, &mut rng);
+
+        let shares: Vec<Share> = [5u8, 77, 190]
+            .into_iter()
+            .map(|x| {
+                let mut y = vec![0u8; secret.len()];
+                coefficients.evaluate_into(GF256(x), &mut y);
+                Share::new(GF256(x), y.into_iter().map(GF256).collect())
+            })
+            .collect();
+
+        assert_eq!(interpolate(&shares), secret);
+    }
+
+    #[test]
+    fn test_coefficients_from_parts() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([10; 32]);
+        let joined = Coefficients::random(&[1, 2, 3, 4], 3, &mut rng);
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([10; 32]);
+        let parts = Coefficients::random_parts(&[&[1, 2], &[], &[3, 4]], 3, &mut rng);
+
+        assert_eq!(joined, parts);
+    }
+
     #[test]
     fn test_round_trip_secret_sharing() {
         let mut rng = rand_chacha::ChaCha8Rng::from_seed([42; 32]);
@@ -366,7 +832,20 @@ mod tests {
             vec![GF256(4), GF256(5), GF256(6)], // degree 2
         ];
 
-        assert!(validate_polynomials(&invalid_polys, 3).is_err());
+        assert_eq!(
+            validate_polynomials(&invalid_polys, 3),
+            Err(ShamirError::PolynomialLengthMismatch {
+                index: 0,
+                expected: 3,
+                actual: 2
+            })
+        );
+
+        let degenerate_polys = vec![vec![GF256(1), GF256(2)], vec![GF256(0), GF256(5)]];
+        assert_eq!(
+            validate_polynomials(&degenerate_polys, 2),
+            Err(ShamirError::ZeroLeadingCoefficient(1))
+        );
     }
 
     #[test]
@@ -375,7 +854,7 @@ mod tests {
         let result = interpolate(&empty_shares);
         assert!(result.is_empty());
 
-        assert!(validate_polynomials(&[], 3).is_err());
+        assert_eq!(validate_polynomials(&[], 3), Err(ShamirError::EmptyInput));
     }
 
     #[test]
@@ -0,0 +1,432 @@
+//! Dense polynomials over a finite field.
+use core::ops::{Add, Mul, Sub};
+
+use crate::field::Field;
+use crate::gf256::GF256;
+use crate::{Result, ShamirError};
+
+/// A polynomial with coefficients in a finite field.
+///
+/// Coefficients are stored in ascending order of degree, so `coefficients()[i]`
+/// is the coefficient of `x^i` and `coefficients()[0]` is the constant term
+/// (the secret, for a sharing polynomial). Trailing zero coefficients are
+/// trimmed, so two polynomials are equal exactly when their coefficients are.
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::gf256::GF256;
+/// use fractus_shamir::poly::Polynomial;
+///
+/// // f(x) = 5 + 3x + 2x^2
+/// let f = Polynomial::new(vec![GF256(5), GF256(3), GF256(2)]);
+/// assert_eq!(f.degree(), Some(2));
+///
+/// let points: Vec<_> = [1, 2, 3]
+///     .into_iter()
+///     .map(|x| (GF256(x), f.evaluate(GF256(x))))
+///     .collect();
+/// assert_eq!(Polynomial::interpolate(&points).unwrap(), f);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial<F = GF256> {
+    coefficients: Vec<F>,
+}
+
+impl<F: Field> Polynomial<F> {
+    /// Creates a polynomial from coefficients in ascending order of degree.
+    pub fn new(coefficients: Vec<F>) -> Self {
+        let mut polynomial = Self { coefficients };
+        polynomial.trim();
+        polynomial
+    }
+
+    /// Creates a polynomial from coefficients in descending order of degree,
+    /// the layout used by [`random_polynomial`](super::random_polynomial) and
+    /// [`evaluate_polynomial`](super::evaluate_polynomial).
+    pub fn from_descending(coefficients: &[F]) -> Self {
+        Self::new(coefficients.iter().rev().copied().collect())
+    }
+
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        Self {
+            coefficients: Vec::new(),
+        }
+    }
+
+    /// Returns the constant polynomial `c`.
+    pub fn constant(c: F) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// Returns the polynomial `c * x^power`.
+    pub fn monomial(c: F, power: usize) -> Self {
+        let mut coefficients = vec![F::ZERO; power + 1];
+        coefficients[power] = c;
+        Self::new(coefficients)
+    }
+
+    /// Returns the coefficients in ascending order of degree, without trailing zeros.
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficient of `x^power`, which is zero beyond the degree.
+    pub fn coefficient(&self, power: usize) -> F {
+        self.coefficients.get(power).copied().unwrap_or(F::ZERO)
+    }
+
+    /// Returns the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Returns true if this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Returns the coefficient of the highest power, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<F> {
+        self.coefficients.last().copied()
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    pub fn evaluate(&self, x: F) -> F {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(F::ZERO, |accumulator, &coefficient| {
+                accumulator * x + coefficient
+            })
+    }
+
+    /// Evaluates the polynomial at each of `xs`.
+    pub fn evaluate_many(&self, xs: &[F]) -> Vec<F> {
+        xs.iter().map(|&x| self.evaluate(x)).collect()
+    }
+
+    /// Multiplies every coefficient by `c`.
+    pub fn scale(&self, c: F) -> Self {
+        Self::new(self.coefficients.iter().map(|&a| a * c).collect())
+    }
+
+    /// Finds the unique polynomial of degree below `points.len()` passing
+    /// through every `(x, y)` point.
+    ///
+    /// Unlike [`interpolate_at`](super::interpolate_at), which only evaluates
+    /// the interpolated polynomial, this returns all of its coefficients.
+    ///
+    /// # Errors
+    /// * `ShamirError::EmptyInput` - No points were given
+    /// * `ShamirError::DuplicatePoints` - Two points share an x-coordinate
+    pub fn interpolate(points: &[(F, F)]) -> Result<Self> {
+        if points.is_empty() {
+            return Err(ShamirError::EmptyInput);
+        }
+
+        // N(x) = (x - x_0)(x - x_1)...(x - x_{n-1})
+        let mut vanishing = Self::constant(F::ONE);
+        for &(x, _) in points {
+            vanishing = &vanishing * &Self::new(vec![F::ZERO - x, F::ONE]);
+        }
+
+        let mut result = vec![F::ZERO; points.len()];
+        for (j, &(xj, yj)) in points.iter().enumerate() {
+            let denominator: F = points
+                .iter()
+                .enumerate()
+                .filter(|&(m, _)| m != j)
+                .map(|(_, &(xm, _))| xj - xm)
+                .product();
+            if denominator.is_zero() {
+                return Err(ShamirError::DuplicatePoints);
+            }
+
+            // The Lagrange basis polynomial for x_j is N(x) / (x - x_j), scaled
+            // so that it is one at x_j
+            let (basis, _) = vanishing.div_rem(&Self::new(vec![F::ZERO - xj, F::ONE]));
+            let weight = yj / denominator;
+            for (power, &coefficient) in basis.coefficients.iter().enumerate() {
+                result[power] = result[power] + coefficient * weight;
+            }
+        }
+
+        Ok(Self::new(result))
+    }
+
+    /// Divides by `divisor`, returning the quotient and remainder.
+    ///
+    /// The remainder is zero or has a degree strictly below the divisor's.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_degree = divisor.degree().expect("division by the zero polynomial");
+        let lead_inverse = divisor.coefficients[divisor_degree].inverse();
+
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() <= divisor_degree {
+            return (Self::zero(), self.clone());
+        }
+
+        let mut quotient = vec![F::ZERO; remainder.len() - divisor_degree];
+        for shift in (0..quotient.len()).rev() {
+            let factor = remainder[shift + divisor_degree] * lead_inverse;
+            quotient[shift] = factor;
+            for (i, &d) in divisor.coefficients.iter().enumerate() {
+                remainder[shift + i] = remainder[shift + i] - factor * d;
+            }
+        }
+
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    fn trim(&mut self) {
+        while self.coefficients.last().is_some_and(Field::is_zero) {
+            self.coefficients.pop();
+        }
+    }
+}
+
+impl<F: Field> Default for Polynomial<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: Field> From<Vec<F>> for Polynomial<F> {
+    fn from(coefficients: Vec<F>) -> Self {
+        Self::new(coefficients)
+    }
+}
+
+impl<F: Field> Add for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn add(self, rhs: Self) -> Polynomial<F> {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial::new(
+            (0..len)
+                .map(|i| self.coefficient(i) + rhs.coefficient(i))
+                .collect(),
+        )
+    }
+}
+
+impl<F: Field> Sub for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn sub(self, rhs: Self) -> Polynomial<F> {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial::new(
+            (0..len)
+                .map(|i| self.coefficient(i) - rhs.coefficient(i))
+                .collect(),
+        )
+    }
+}
+
+impl<F: Field> Mul for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: Self) -> Polynomial<F> {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+
+        let mut product = vec![F::ZERO; self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in rhs.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j] + a * b;
+            }
+        }
+        Polynomial::new(product)
+    }
+}
+
+impl<F: Field> Mul<F> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: F) -> Polynomial<F> {
+        self.scale(rhs)
+    }
+}
+
+macro_rules! forward_owned_ops {
+    ($($op:ident::$method:ident),*) => {$(
+        impl<F: Field> $op for Polynomial<F> {
+            type Output = Polynomial<F>;
+
+            fn $method(self, rhs: Self) -> Polynomial<F> {
+                (&self).$method(&rhs)
+            }
+        }
+    )*};
+}
+
+forward_owned_ops!(Add::add, Sub::sub, Mul::mul);
+
+impl<F: Field> Mul<F> for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: F) -> Polynomial<F> {
+        self.scale(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use rand_chacha::rand_core::SeedableRng;
+
+    fn poly(coefficients: &[u8]) -> Polynomial {
+        Polynomial::new(coefficients.iter().copied().map(GF256).collect())
+    }
+
+    fn random_poly<R: Rng>(len: usize, rng: &mut R) -> Polynomial {
+        Polynomial::new((0..len).map(|_| GF256(rng.r#gen())).collect())
+    }
+
+    #[test]
+    fn test_normalization_and_degree() {
+        assert_eq!(poly(&[1, 2, 0, 0]), poly(&[1, 2]));
+        assert_eq!(poly(&[1, 2]).degree(), Some(1));
+        assert_eq!(poly(&[7]).degree(), Some(0));
+        assert_eq!(poly(&[0, 0]).degree(), None);
+        assert!(poly(&[0]).is_zero());
+        assert_eq!(Polynomial::monomial(GF256(3), 4).degree(), Some(4));
+        assert_eq!(Polynomial::monomial(GF256(0), 4), Polynomial::zero());
+        assert_eq!(poly(&[1, 2, 3]).leading_coefficient(), Some(GF256(3)));
+        assert_eq!(poly(&[1, 2, 3]).coefficient(10), GF256::ZERO);
+    }
+
+    #[test]
+    fn test_matches_legacy_evaluation() {
+        let descending = [GF256(2), GF256(3), GF256(5)];
+        let polynomial = Polynomial::from_descending(&descending);
+        assert_eq!(polynomial, poly(&[5, 3, 2]));
+
+        for x in 0..=255 {
+            assert_eq!(
+                polynomial.evaluate(GF256(x)),
+                super::super::evaluate_polynomial(&descending, GF256(x))
+            );
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_is_consistent_with_evaluation() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([33; 32]);
+        for _ in 0..50 {
+            let a = random_poly(rng.gen_range(0..8), &mut rng);
+            let b = random_poly(rng.gen_range(0..8), &mut rng);
+            let c = GF256(rng.r#gen());
+
+            let sum = &a + &b;
+            let difference = &a - &b;
+            let product = &a * &b;
+            let scaled = &a * c;
+
+            for x in [0u8, 1, 2, 77, 255].map(GF256) {
+                assert_eq!(sum.evaluate(x), a.evaluate(x) + b.evaluate(x));
+                assert_eq!(difference.evaluate(x), a.evaluate(x) - b.evaluate(x));
+                assert_eq!(product.evaluate(x), a.evaluate(x) * b.evaluate(x));
+                assert_eq!(scaled.evaluate(x), a.evaluate(x) * c);
+            }
+        }
+    }
+
+    #[test]
+    fn test_degree_of_products_and_sums() {
+        let a = poly(&[1, 2, 3]);
+        let b = poly(&[4, 5]);
+        assert_eq!((&a * &b).degree(), Some(3));
+        assert_eq!(&a * &Polynomial::zero(), Polynomial::zero());
+        // Characteristic 2: every polynomial is its own additive inverse
+        assert!((&a + &a).is_zero());
+        assert_eq!(a.clone() - a.clone(), Polynomial::zero());
+        assert_eq!(a.clone() * GF256::ZERO, Polynomial::zero());
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([34; 32]);
+        for _ in 0..50 {
+            let a = random_poly(rng.gen_range(0..10), &mut rng);
+            let mut b = random_poly(rng.gen_range(1..6), &mut rng);
+            if b.is_zero() {
+                b = Polynomial::constant(GF256(1));
+            }
+
+            let (quotient, remainder) = a.div_rem(&b);
+            assert_eq!(&(&quotient * &b) + &remainder, a);
+            assert!(remainder.degree() < b.degree());
+        }
+    }
+
+    #[test]
+    fn test_div_rem_exact() {
+        let a = poly(&[3, 1]);
+        let b = poly(&[9, 8, 7]);
+        let (quotient, remainder) = (&a * &b).div_rem(&b);
+        assert_eq!(quotient, a);
+        assert!(remainder.is_zero());
+
+        // Dividing a lower-degree polynomial leaves it as the remainder
+        assert_eq!(a.div_rem(&b), (Polynomial::zero(), a.clone()));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by the zero polynomial")]
+    fn test_div_rem_by_zero() {
+        poly(&[1, 2]).div_rem(&Polynomial::zero());
+    }
+
+    #[test]
+    fn test_interpolate_recovers_coefficients() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([35; 32]);
+        for len in 1..12 {
+            let mut coefficients: Vec<GF256> = (0..len).map(|_| GF256(rng.r#gen())).collect();
+            coefficients[len - 1] = GF256(1 + rng.gen_range(0..255));
+            let polynomial = Polynomial::new(coefficients);
+
+            let points: Vec<_> = (1..=len as u8)
+                .map(|x| (GF256(x * 3), polynomial.evaluate(GF256(x * 3))))
+                .collect();
+            assert_eq!(Polynomial::interpolate(&points).unwrap(), polynomial);
+        }
+    }
+
+    #[test]
+    fn test_interpolate_agrees_with_share_interpolation() {
+        let shamir = crate::Shamir::new(3).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([36; 32]);
+        let shares: Vec<_> = shamir
+            .split_with_rng(b"abc", &mut rng)
+            .unwrap()
+            .take(3)
+            .collect();
+
+        let points: Vec<_> = shares.iter().map(|s| (s.x, s.y[1])).collect();
+        let polynomial = Polynomial::interpolate(&points).unwrap();
+        assert_eq!(polynomial.evaluate(GF256::ZERO), GF256(b'b'));
+        assert_eq!(
+            polynomial.evaluate(GF256(200)),
+            GF256(super::super::interpolate_at(&shares, GF256(200))[1])
+        );
+    }
+
+    #[test]
+    fn test_interpolate_errors() {
+        assert_eq!(
+            Polynomial::<GF256>::interpolate(&[]),
+            Err(ShamirError::EmptyInput)
+        );
+        assert_eq!(
+            Polynomial::interpolate(&[(GF256(1), GF256(2)), (GF256(1), GF256(3))]),
+            Err(ShamirError::DuplicatePoints)
+        );
+    }
+}
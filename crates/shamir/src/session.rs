@@ -0,0 +1,484 @@
+//! Incremental recovery for shares that arrive one at a time.
+use super::gf256::{GF256, as_bytes};
+use super::share::Share;
+use super::{Result, Shamir, ShamirError, poly};
+
+/// A problem detected while adding a share to a [`RecoverySession`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conflict {
+    /// A share identical to one already added was submitted again; it was ignored
+    Duplicate { x: u8 },
+    /// A share reuses the x-coordinate of an earlier share but carries different
+    /// data; the earlier share was kept
+    ConflictingDuplicate { x: u8 },
+    /// A share's length differs from the shares added before it; it was rejected
+    LengthMismatch {
+        x: u8,
+        expected: usize,
+        actual: usize,
+    },
+    /// A surplus share does not lie on the polynomial interpolated from the first
+    /// `threshold` shares; either it or one of those shares is corrupted. The
+    /// share is kept, but not counted, until
+    /// [`RecoverySession::remove_share`] changes those shares.
+    Inconsistent { x: u8 },
+}
+
+impl std::fmt::Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Duplicate { x } => write!(f, "Share {} was already added", x),
+            Self::ConflictingDuplicate { x } => write!(
+                f,
+                "Share {} conflicts with an earlier share with the same x-coordinate",
+                x
+            ),
+            Self::LengthMismatch {
+                x,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Share {} has length {}, but earlier shares have length {}",
+                x, actual, expected
+            ),
+            Self::Inconsistent { x } => {
+                write!(f, "Share {} is inconsistent with the other shares", x)
+            }
+        }
+    }
+}
+
+/// Progress of a [`RecoverySession`] after a share was added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Status {
+    /// Number of distinct shares accepted so far
+    pub have: usize,
+    /// Number of further shares required before the secret can be recovered
+    pub need: usize,
+    /// Every problem detected so far, in the order the shares arrived
+    pub conflicts: Vec<Conflict>,
+}
+
+impl Status {
+    /// Returns true if enough shares have been accepted to recover the secret.
+    pub fn is_ready(&self) -> bool {
+        self.need == 0
+    }
+}
+
+/// Collects shares one at a time and recovers the secret once enough arrived.
+///
+/// Each share is checked as soon as it is added: duplicates and length
+/// mismatches are reported immediately, and once `threshold` shares are held
+/// every further share is checked against the polynomial they define.
+///
+/// The first `threshold` shares define that polynomial and are the only
+/// shares held in full. A surplus share that lies on it is only remembered by
+/// its x-coordinate, as the polynomial gives back its data. If one of the
+/// first shares is corrupted, good surplus shares are reported as
+/// [`Conflict::Inconsistent`]; removing the corrupted share with
+/// [`RecoverySession::remove_share`] lets the next share take its place, and
+/// every surplus share is checked again. Inconsistent surplus shares are
+/// therefore kept whole until then.
+///
+/// # Examples
+/// ```
+/// use fractus_shamir::{RecoverySession, Shamir};
+/// use rand_chacha::rand_core::SeedableRng;
+///
+/// let shamir = Shamir::new(2).unwrap();
+/// let mut rng = rand_chacha::ChaCha8Rng::from_seed([0x90; 32]);
+/// let mut shares = shamir.split_with_rng(b"Hello world!", &mut rng).unwrap();
+///
+/// let mut session = RecoverySession::new(2).unwrap();
+/// let status = session.add_share(shares.next().unwrap());
+/// assert_eq!((status.have, status.need), (1, 1));
+///
+/// let status = session.add_share(shares.next().unwrap());
+/// assert!(status.is_ready());
+///
+/// // Surplus shares are validated against the others
+/// let status = session.add_share(shares.next().unwrap());
+/// assert!(status.conflicts.is_empty());
+///
+/// assert_eq!(session.finalize().unwrap(), b"Hello world!");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecoverySession {
+    threshold: u8,
+    /// The first `threshold` accepted shares, in the order they arrived
+    basis: Vec<Share>,
+    /// Shares accepted after the basis was complete, in the order they arrived
+    surplus: Vec<Surplus>,
+    conflicts: Vec<Conflict>,
+}
+
+/// A share accepted after the first `threshold`
+#[derive(Debug, Clone)]
+enum Surplus {
+    /// The x-coordinate of a share on the polynomial of the basis
+    Consistent(u8),
+    /// A share off that polynomial
+    Inconsistent(Share),
+}
+
+impl RecoverySession {
+    /// Creates a session that needs `threshold` shares to recover the secret.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidThreshold` if threshold is 0.
+    pub fn new(threshold: u8) -> Result<Self> {
+        if threshold == 0 {
+            return Err(ShamirError::InvalidThreshold);
+        }
+
+        Ok(Self {
+            threshold,
+            basis: Vec::with_capacity(threshold as usize),
+            surplus: Vec::new(),
+            conflicts: Vec::new(),
+        })
+    }
+
+    /// Returns the number of shares required to recover the secret.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Adds a share to the session and reports the resulting progress.
+    ///
+    /// Shares that conflict with earlier ones are not counted; the reason is
+    /// recorded in [`Status::conflicts`].
+    pub fn add_share(&mut self, share: Share) -> Status {
+        match self.check(&share) {
+            Some(conflict @ Conflict::Inconsistent { .. }) => {
+                self.conflicts.push(conflict);
+                self.surplus.push(Surplus::Inconsistent(share));
+            }
+            Some(conflict) => self.conflicts.push(conflict),
+            None if self.basis.len() < self.threshold as usize => self.basis.push(share),
+            None => self.surplus.push(Surplus::Consistent(share.x.0)),
+        }
+
+        self.status()
+    }
+
+    /// Removes the share with x-coordinate `x`, returning it if it was held.
+    ///
+    /// Use this to drop a share suspected of corruption. If it was one of the
+    /// first `threshold` shares, the next share to arrive takes its place and
+    /// every surplus share is checked again, so [`Conflict::Inconsistent`]
+    /// entries in [`Status::conflicts`] always describe the current shares.
+    /// Other conflicts are kept as a record of what was submitted. A surplus
+    /// share on the polynomial is returned as recomputed from the others.
+    pub fn remove_share(&mut self, x: u8) -> Option<Share> {
+        // Rebuild every share in arrival order, recomputing consistent surplus
+        // shares from the basis before it changes
+        let mut shares = std::mem::take(&mut self.basis);
+        let surplus: Vec<Share> = std::mem::take(&mut self.surplus)
+            .into_iter()
+            .map(|surplus| match surplus {
+                Surplus::Consistent(x) => Self::on_basis(&shares, x),
+                Surplus::Inconsistent(share) => share,
+            })
+            .collect();
+        shares.extend(surplus);
+        let removed = shares
+            .iter()
+            .position(|share| share.x.0 == x)
+            .map(|index| shares.remove(index));
+
+        self.conflicts
+            .retain(|conflict| !matches!(conflict, Conflict::Inconsistent { .. }));
+        let surplus = shares.split_off(shares.len().min(self.threshold as usize));
+        self.basis = shares;
+        for share in surplus {
+            if Self::on_basis(&self.basis, share.x.0) == share {
+                self.surplus.push(Surplus::Consistent(share.x.0));
+            } else {
+                self.conflicts.push(Conflict::Inconsistent { x: share.x.0 });
+                self.surplus.push(Surplus::Inconsistent(share));
+            }
+        }
+
+        removed
+    }
+
+    /// Returns the current progress without adding a share.
+    pub fn status(&self) -> Status {
+        let consistent = self
+            .surplus
+            .iter()
+            .filter(|surplus| matches!(surplus, Surplus::Consistent(_)))
+            .count();
+        Status {
+            have: self.basis.len() + consistent,
+            need: (self.threshold as usize).saturating_sub(self.basis.len()),
+            conflicts: self.conflicts.clone(),
+        }
+    }
+
+    /// Recovers the secret from the accepted shares.
+    ///
+    /// # Errors
+    /// * `ShamirError::InsufficientShares` - Fewer than `threshold` shares were accepted
+    /// * `ShamirError::ChecksumMismatch` - Recovered data fails integrity check
+    pub fn finalize(self) -> Result<Vec<u8>> {
+        Shamir::new(self.threshold)?.recover(&self.basis)
+    }
+
+    /// The share at `x` on the polynomial through `basis`
+    fn on_basis(basis: &[Share], x: u8) -> Share {
+        let y = poly::interpolate_at(basis, GF256(x));
+        Share::new(GF256(x), y.into_iter().map(GF256).collect())
+    }
+
+    fn check(&self, share: &Share) -> Option<Conflict> {
+        let x = share.x.0;
+
+        if let Some(expected) = self.basis.first().map(|first| first.y.len())
+            && share.y.len() != expected
+        {
+            return Some(Conflict::LengthMismatch {
+                x,
+                expected,
+                actual: share.y.len(),
+            });
+        }
+
+        let on_basis = self.basis.len() >= self.threshold as usize
+            && poly::interpolate_at(&self.basis, share.x) == as_bytes(&share.y);
+        let duplicate = self
+            .basis
+            .iter()
+            .find(|kept| kept.x == share.x)
+            .map(|kept| kept == share)
+            .or_else(|| {
+                self.surplus.iter().find_map(|surplus| match surplus {
+                    Surplus::Consistent(kept) => (*kept == x).then_some(on_basis),
+                    Surplus::Inconsistent(kept) => (kept.x == share.x).then(|| kept == share),
+                })
+            });
+        match duplicate {
+            Some(true) => return Some(Conflict::Duplicate { x }),
+            Some(false) => return Some(Conflict::ConflictingDuplicate { x }),
+            None => {}
+        }
+
+        if self.basis.len() >= self.threshold as usize && !on_basis {
+            return Some(Conflict::Inconsistent { x });
+        }
+
+        None
+    }
+}
+
+impl From<&Shamir> for RecoverySession {
+    fn from(shamir: &Shamir) -> Self {
+        Self::new(shamir.threshold()).expect("Shamir thresholds are never zero")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf256::GF256;
+    use rand_chacha::rand_core::SeedableRng;
+
+    fn shares(threshold: u8, count: usize, secret: &[u8]) -> Vec<Share> {
+        let shamir = Shamir::new(threshold).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([30; 32]);
+        shamir
+            .split_with_rng(secret, &mut rng)
+            .unwrap()
+            .take(count)
+            .collect()
+    }
+
+    #[test]
+    fn test_session_progress() {
+        let shares = shares(3, 5, b"key ceremony");
+        let mut session = RecoverySession::new(3).unwrap();
+
+        for (i, share) in shares.into_iter().enumerate() {
+            let status = session.add_share(share);
+            assert_eq!(status.have, i + 1);
+            assert_eq!(status.need, 2usize.saturating_sub(i));
+            assert!(status.conflicts.is_empty());
+        }
+
+        assert_eq!(session.finalize().unwrap(), b"key ceremony");
+    }
+
+    #[test]
+    fn test_session_insufficient_shares() {
+        let shares = shares(3, 2, b"not enough");
+        let mut session = RecoverySession::new(3).unwrap();
+        for share in shares {
+            session.add_share(share);
+        }
+
+        assert_eq!(
+            session.finalize(),
+            Err(ShamirError::InsufficientShares {
+                required: 3,
+                provided: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_session_duplicates() {
+        let shares = shares(2, 3, b"duplicates");
+        let mut session = RecoverySession::new(2).unwrap();
+
+        session.add_share(shares[0].clone());
+        let status = session.add_share(shares[0].clone());
+        assert_eq!(status.have, 1);
+        assert_eq!(status.conflicts, vec![Conflict::Duplicate { x: 1 }]);
+
+        let mut tampered = shares[0].clone();
+        tampered.y[0] = tampered.y[0] + GF256(1);
+        let status = session.add_share(tampered);
+        assert_eq!(status.have, 1);
+        assert_eq!(status.conflicts[1], Conflict::ConflictingDuplicate { x: 1 });
+
+        // Duplicates of surplus shares are recognised as well
+        session.add_share(shares[1].clone());
+        session.add_share(shares[2].clone());
+        let status = session.add_share(shares[2].clone());
+        assert_eq!(status.have, 3);
+        assert_eq!(status.conflicts[2], Conflict::Duplicate { x: 3 });
+
+        assert_eq!(session.finalize().unwrap(), b"duplicates");
+    }
+
+    #[test]
+    fn test_session_length_mismatch() {
+        let shares = shares(2, 2, b"length");
+        let mut session = RecoverySession::new(2).unwrap();
+        session.add_share(shares[0].clone());
+
+        let mut short = shares[1].clone();
+        short.y.pop();
+        let status = session.add_share(short);
+        assert_eq!(status.have, 1);
+        assert_eq!(
+            status.conflicts,
+            vec![Conflict::LengthMismatch {
+                x: 2,
+                expected: 10,
+                actual: 9
+            }]
+        );
+    }
+
+    #[test]
+    fn test_session_detects_inconsistent_surplus_share() {
+        let shares = shares(3, 5, b"surplus");
+        let mut session = RecoverySession::new(3).unwrap();
+        for share in &shares[..3] {
+            session.add_share(share.clone());
+        }
+
+        let mut corrupted = shares[3].clone();
+        corrupted.y[2] = corrupted.y[2] + GF256(0x40);
+        let status = session.add_share(corrupted);
+        assert_eq!(status.have, 3);
+        assert_eq!(status.conflicts, vec![Conflict::Inconsistent { x: 4 }]);
+
+        let status = session.add_share(shares[4].clone());
+        assert_eq!(status.have, 4);
+        assert_eq!(status.conflicts.len(), 1);
+
+        assert_eq!(session.finalize().unwrap(), b"surplus");
+    }
+
+    #[test]
+    fn test_session_recovers_after_removing_corrupted_basis_share() {
+        let shares = shares(3, 6, b"bad basis");
+        let mut session = RecoverySession::new(3).unwrap();
+
+        let mut corrupted = shares[1].clone();
+        corrupted.y[0] = corrupted.y[0] + GF256(0x11);
+        session.add_share(shares[0].clone());
+        session.add_share(corrupted);
+        session.add_share(shares[2].clone());
+
+        // Good shares disagree with the corrupted basis
+        let mut status = session.status();
+        for share in &shares[3..] {
+            status = session.add_share(share.clone());
+        }
+        assert_eq!(status.have, 3);
+        assert_eq!(
+            status.conflicts,
+            vec![
+                Conflict::Inconsistent { x: 4 },
+                Conflict::Inconsistent { x: 5 },
+                Conflict::Inconsistent { x: 6 }
+            ]
+        );
+
+        assert!(session.remove_share(2).is_some());
+        assert!(session.remove_share(2).is_none());
+        let status = session.status();
+        assert_eq!((status.have, status.need), (5, 0));
+        assert!(status.conflicts.is_empty());
+
+        assert_eq!(session.finalize().unwrap(), b"bad basis");
+    }
+
+    #[test]
+    fn test_session_remove_share_below_threshold() {
+        let shares = shares(3, 3, b"removed");
+        let mut session = RecoverySession::new(3).unwrap();
+        session.add_share(shares[0].clone());
+        session.add_share(shares[1].clone());
+
+        assert_eq!(session.remove_share(1), Some(shares[0].clone()));
+        let status = session.add_share(shares[2].clone());
+        assert_eq!((status.have, status.need), (2, 1));
+    }
+
+    #[test]
+    fn test_session_keeps_only_the_basis_and_inconsistent_shares() {
+        let shares = shares(2, 5, b"retention");
+        let mut session = RecoverySession::new(2).unwrap();
+        for share in &shares[..4] {
+            session.add_share(share.clone());
+        }
+        let mut corrupted = shares[4].clone();
+        corrupted.y[1] = corrupted.y[1] + GF256(0x08);
+        session.add_share(corrupted.clone());
+
+        assert_eq!(session.basis, shares[..2]);
+        assert!(matches!(
+            session.surplus[..],
+            [
+                Surplus::Consistent(3),
+                Surplus::Consistent(4),
+                Surplus::Inconsistent(_)
+            ]
+        ));
+
+        // Dropped shares are recomputed when removed or when the basis changes
+        assert_eq!(session.remove_share(4), Some(shares[3].clone()));
+        assert_eq!(session.remove_share(1), Some(shares[0].clone()));
+        assert_eq!(session.basis, [shares[1].clone(), shares[2].clone()]);
+        assert_eq!(session.remove_share(5), Some(corrupted));
+        assert_eq!(session.status().have, 2);
+
+        assert_eq!(session.finalize().unwrap(), b"retention");
+    }
+
+    #[test]
+    fn test_session_from_shamir() {
+        let shamir = Shamir::new(4).unwrap();
+        let session = RecoverySession::from(&shamir);
+        assert_eq!(session.threshold(), 4);
+        assert_eq!(session.status().need, 4);
+        assert!(RecoverySession::new(0).is_err());
+    }
+}
@@ -1,5 +1,8 @@
 //! Share representation and serialization for Shamir's Secret Sharing.
+use core::ops::{Add, Mul, Sub};
+
 use super::gf256::GF256;
+use super::{CHECKSUM_LEN, ShamirError};
 
 /// A single share in Shamir's Secret Sharing scheme.
 ///
@@ -62,6 +65,206 @@ impl Share {
         let y = bytes[1..].iter().map(|&b| GF256(b)).collect();
         Ok(Self { x, y })
     }
+
+    /// Adds two shares with the same x-coordinate.
+    ///
+    /// Shares are linearly homomorphic: the sum of shares of two secrets is a
+    /// share of their XOR (addition in GF(256)), and recovers with a valid
+    /// checksum. Both shares must come from splits with the same threshold, or
+    /// the result recovers with the larger of the two.
+    ///
+    /// # Errors
+    /// * `ShamirError::XCoordinateMismatch` - The shares have different x-coordinates
+    /// * `ShamirError::InconsistentShareLength` - The shares have different lengths
+    ///
+    /// # Examples
+    /// ```
+    /// use fractus_shamir::Shamir;
+    ///
+    /// let shamir = Shamir::new(2).unwrap();
+    /// let a: Vec<_> = shamir.split(b"\x0f\x0f").unwrap().take(2).collect();
+    /// let b: Vec<_> = shamir.split(b"\x01\x10").unwrap().take(2).collect();
+    ///
+    /// let sum: Vec<_> = a.iter().zip(&b).map(|(a, b)| a.checked_add(b).unwrap()).collect();
+    /// assert_eq!(shamir.recover(&sum).unwrap(), b"\x0e\x1f");
+    /// ```
+    pub fn checked_add(&self, other: &Share) -> super::Result<Share> {
+        let mut sum = self.clone();
+        sum.try_add_assign(other)?;
+        Ok(sum)
+    }
+
+    /// Computes `sum(coefficient * share)` over shares with the same
+    /// x-coordinate, giving a share of the same combination of their secrets.
+    ///
+    /// The embedded checksum only survives addition: unless every coefficient
+    /// is one, recover the result with [`Shamir::recover_unchecked`].
+    ///
+    /// [`Shamir::recover_unchecked`]: super::Shamir::recover_unchecked
+    ///
+    /// # Errors
+    /// * `ShamirError::EmptyInput` - No terms were given
+    /// * `ShamirError::XCoordinateMismatch` - The shares have different x-coordinates
+    /// * `ShamirError::InconsistentShareLength` - The shares have different lengths
+    ///
+    /// # Examples
+    /// ```
+    /// use fractus_shamir::{Shamir, Share, gf256::GF256};
+    ///
+    /// let shamir = Shamir::new(2).unwrap();
+    /// let a: Vec<_> = shamir.split(&[3]).unwrap().take(2).collect();
+    /// let b: Vec<_> = shamir.split(&[5]).unwrap().take(2).collect();
+    ///
+    /// // Shares of 2 * 3 + 5 in GF(256)
+    /// let combined: Vec<_> = a
+    ///     .iter()
+    ///     .zip(&b)
+    ///     .map(|(a, b)| Share::linear_combination([(GF256(2), a), (GF256(1), b)]).unwrap())
+    ///     .collect();
+    /// assert_eq!(shamir.recover_unchecked(&combined).unwrap(), vec![(GF256(2) * GF256(3) + GF256(5)).0]);
+    /// ```
+    pub fn linear_combination<'a, I>(terms: I) -> super::Result<Share>
+    where
+        I: IntoIterator<Item = (GF256, &'a Share)>,
+    {
+        let mut terms = terms.into_iter();
+        let (coefficient, first) = terms.next().ok_or(ShamirError::EmptyInput)?;
+        let mut sum = first * coefficient;
+        for (coefficient, share) in terms {
+            sum.try_add_assign(&(share * coefficient))?;
+        }
+        Ok(sum)
+    }
+
+    fn check_compatible(&self, other: &Share) -> super::Result<()> {
+        if self.x != other.x {
+            return Err(ShamirError::XCoordinateMismatch {
+                left: self.x.0,
+                right: other.x.0,
+            });
+        }
+        if self.y.len() != other.y.len() {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+        Ok(())
+    }
+
+    fn try_add_assign(&mut self, other: &Share) -> super::Result<()> {
+        self.check_compatible(other)?;
+        for (a, b) in self.y.iter_mut().zip(&other.y) {
+            *a = *a + *b;
+        }
+
+        // CRC32 is affine, crc(a ^ b) = crc(a) ^ crc(b) ^ crc(0...0), so the
+        // summed checksums are off by a constant that can be added to every
+        // share alike
+        if let Some(secret_len) = self.y.len().checked_sub(CHECKSUM_LEN) {
+            for (y, c) in self.y[secret_len..]
+                .iter_mut()
+                .zip(zero_checksum(secret_len))
+            {
+                *y = *y + GF256(c);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The big-endian CRC32 of `len` zero bytes.
+fn zero_checksum(len: usize) -> [u8; CHECKSUM_LEN] {
+    const ZEROS: [u8; 256] = [0; 256];
+    let mut hasher = crc32fast::Hasher::new();
+    let mut remaining = len;
+    while remaining > 0 {
+        let chunk = remaining.min(ZEROS.len());
+        hasher.update(&ZEROS[..chunk]);
+        remaining -= chunk;
+    }
+    hasher.finalize().to_be_bytes()
+}
+
+/// Adds two shares; see [`Share::checked_add`].
+///
+/// # Panics
+/// Panics if the shares have different x-coordinates or lengths.
+impl Add<&Share> for &Share {
+    type Output = Share;
+
+    fn add(self, other: &Share) -> Share {
+        self.clone() + other
+    }
+}
+
+impl Add<&Share> for Share {
+    type Output = Share;
+
+    fn add(mut self, other: &Share) -> Share {
+        if let Err(e) = self.try_add_assign(other) {
+            panic!("Cannot add shares: {}", e);
+        }
+        self
+    }
+}
+
+impl Add for Share {
+    type Output = Share;
+
+    fn add(self, other: Share) -> Share {
+        self + &other
+    }
+}
+
+/// Subtracts two shares, which in GF(256) is the same as adding them.
+///
+/// # Panics
+/// Panics if the shares have different x-coordinates or lengths.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub<&Share> for &Share {
+    type Output = Share;
+
+    fn sub(self, other: &Share) -> Share {
+        self + other
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub<&Share> for Share {
+    type Output = Share;
+
+    fn sub(self, other: &Share) -> Share {
+        self + other
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub for Share {
+    type Output = Share;
+
+    fn sub(self, other: Share) -> Share {
+        self + &other
+    }
+}
+
+/// Multiplies every byte of a share by a scalar, giving a share of the
+/// scaled secret. The embedded checksum is scaled too and no longer matches,
+/// see [`Share::linear_combination`].
+impl Mul<GF256> for &Share {
+    type Output = Share;
+
+    fn mul(self, scalar: GF256) -> Share {
+        self.clone() * scalar
+    }
+}
+
+impl Mul<GF256> for Share {
+    type Output = Share;
+
+    fn mul(mut self, scalar: GF256) -> Share {
+        for y in &mut self.y {
+            *y = *y * scalar;
+        }
+        self
+    }
 }
 
 impl std::fmt::Display for Share {
@@ -124,4 +327,117 @@ mod tests {
         let display = format!("{}", share);
         assert_eq!(display, "Share(x=1, y=[2, 3])");
     }
+
+    #[test]
+    fn test_zero_checksum() {
+        for len in [0, 1, 255, 256, 257, 1000] {
+            assert_eq!(
+                zero_checksum(len),
+                crc32fast::hash(&vec![0u8; len]).to_be_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn test_sums_preserve_checksum() {
+        use crate::Shamir;
+        use rand_chacha::rand_core::SeedableRng;
+
+        let shamir = Shamir::new(3).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([7; 32]);
+        let secrets: [&[u8]; 3] = [b"first secret", b"other secret", b"third secret"];
+        let shares: Vec<Vec<Share>> = secrets
+            .iter()
+            .map(|secret| {
+                shamir
+                    .split_with_rng(secret, &mut rng)
+                    .unwrap()
+                    .take(4)
+                    .collect()
+            })
+            .collect();
+        let xor = |a: &[u8], b: &[u8]| -> Vec<u8> { a.iter().zip(b).map(|(a, b)| a ^ b).collect() };
+
+        let sum: Vec<Share> = (0..4).map(|i| &shares[0][i] + &shares[1][i]).collect();
+        assert_eq!(
+            shamir.recover(&sum[1..]).unwrap(),
+            xor(secrets[0], secrets[1])
+        );
+
+        let sum3: Vec<Share> = (0..4)
+            .map(|i| shares[0][i].clone() + &shares[1][i] + &shares[2][i])
+            .collect();
+        assert_eq!(
+            shamir.recover(&sum3).unwrap(),
+            xor(&xor(secrets[0], secrets[1]), secrets[2])
+        );
+
+        // Subtracting a share takes its secret back out
+        let difference: Vec<Share> = (0..4).map(|i| &sum3[i] - &shares[2][i]).collect();
+        assert_eq!(
+            shamir.recover(&difference).unwrap(),
+            xor(secrets[0], secrets[1])
+        );
+    }
+
+    #[test]
+    fn test_linear_combination() {
+        use crate::Shamir;
+        use rand_chacha::rand_core::SeedableRng;
+
+        let shamir = Shamir::new(2).unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([9; 32]);
+        let a: Vec<Share> = shamir
+            .split_with_rng(&[1, 2, 3], &mut rng)
+            .unwrap()
+            .take(3)
+            .collect();
+        let b: Vec<Share> = shamir
+            .split_with_rng(&[4, 5, 6], &mut rng)
+            .unwrap()
+            .take(3)
+            .collect();
+
+        let (s, t) = (GF256(0x53), GF256(0xca));
+        let combined: Vec<Share> = (0..3)
+            .map(|i| Share::linear_combination([(s, &a[i]), (t, &b[i])]).unwrap())
+            .collect();
+        let expected: Vec<u8> = [1, 2, 3]
+            .iter()
+            .zip([4, 5, 6])
+            .map(|(&a, b)| (s * GF256(a) + t * GF256(b)).0)
+            .collect();
+        assert_eq!(shamir.recover_unchecked(&combined[1..]).unwrap(), expected);
+
+        // Unit coefficients are plain addition, which keeps the checksum
+        let sum = Share::linear_combination([(GF256(1), &a[0]), (GF256(1), &b[0])]).unwrap();
+        assert_eq!(sum, &a[0] + &b[0]);
+    }
+
+    #[test]
+    fn test_arithmetic_errors() {
+        let a = Share::new(GF256(1), vec![GF256(1); 6]);
+        let b = Share::new(GF256(2), vec![GF256(1); 6]);
+        let c = Share::new(GF256(1), vec![GF256(1); 5]);
+
+        assert_eq!(
+            a.checked_add(&b),
+            Err(ShamirError::XCoordinateMismatch { left: 1, right: 2 })
+        );
+        assert_eq!(a.checked_add(&c), Err(ShamirError::InconsistentShareLength));
+        assert_eq!(
+            Share::linear_combination([(GF256(1), &a), (GF256(0), &b)]),
+            Err(ShamirError::XCoordinateMismatch { left: 1, right: 2 })
+        );
+        assert_eq!(
+            Share::linear_combination(core::iter::empty()),
+            Err(ShamirError::EmptyInput)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add shares")]
+    fn test_add_panics_on_mismatch() {
+        let _ = Share::new(GF256(1), vec![GF256(1)]) + Share::new(GF256(2), vec![GF256(1)]);
+    }
 }
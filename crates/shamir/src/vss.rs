@@ -0,0 +1,586 @@
+//! Verifiable secret sharing over the Ristretto255 group.
+//!
+//! [`Shamir`](super::Shamir) shares live in GF(256), which has no group to
+//! commit to its coefficients in. This module shares secrets over the scalar
+//! field of Ristretto255 instead, so the dealer can publish [`Commitments`] to
+//! every coefficient and each holder can check its share against them before
+//! anything is recovered ([`Commitments::verify`]). A dealer that hands out
+//! inconsistent shares is caught by whoever received a bad one.
+//!
+//! The secret is cut into 31-byte chunks, each of which fits in a scalar and
+//! gets its own polynomial. Two schemes are supported:
+//!
+//! * [`Scheme::Feldman`] commits to each coefficient `a` as `a·G`. The
+//!   commitments reveal `s·G` for every chunk `s` of the secret, so a guessable
+//!   secret can be brute-forced from them.
+//! * [`Scheme::Pedersen`] commits to `a·G + b·H` with a random blinding
+//!   polynomial, where `H` is a second generator nobody knows the discrete
+//!   logarithm of. The commitments reveal nothing about the secret; each share
+//!   carries the blinding polynomial's value next to its own.
+//!
+//! # Examples
+//! ```
+//! use fractus_shamir::vss::{Scheme, Vss};
+//!
+//! let vss = Vss::new(2, Scheme::Pedersen).unwrap();
+//! let (commitments, shares) = vss
+//!     .split_with_rng(b"launch codes", 3, &mut rand::thread_rng())
+//!     .unwrap();
+//!
+//! // Every holder can check its own share against the published commitments
+//! for share in &shares {
+//!     commitments.verify(share).unwrap();
+//! }
+//! assert_eq!(commitments.recover(&shares[1..]).unwrap(), b"launch codes");
+//! ```
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+use curve25519_dalek::traits::{Identity, VartimeMultiscalarMul};
+use hashbrown::HashSet;
+use rand::{CryptoRng, RngCore};
+use sha2::Sha512;
+
+pub use curve25519_dalek::scalar::Scalar;
+
+use super::{Result, ShamirError};
+
+/// Secret bytes per scalar; 31 bytes are always below the group order.
+const CHUNK_LEN: usize = 31;
+
+/// Length of a serialized scalar or compressed point.
+const ELEMENT_LEN: usize = 32;
+
+/// Input for deriving the Pedersen generator `H`, versioned so it can change.
+const PEDERSEN_DOMAIN: &[u8] = b"fractus-shamir/vss/pedersen-generator/v1";
+
+/// How coefficients are committed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scheme {
+    /// Commitments `a·G`, which reveal `s·G` for the secret `s`
+    Feldman,
+    /// Hiding commitments `a·G + b·H` with a random blinding polynomial
+    Pedersen,
+}
+
+impl Scheme {
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Feldman => 0,
+            Self::Pedersen => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self> {
+        match byte {
+            0 => Ok(Self::Feldman),
+            1 => Ok(Self::Pedersen),
+            _ => Err(ShamirError::InvalidEncoding),
+        }
+    }
+}
+
+/// Dealer parameters for verifiable secret sharing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vss {
+    threshold: u8,
+    scheme: Scheme,
+}
+
+/// The dealer's public commitments to the polynomials of one split.
+///
+/// They also record the threshold and the secret length, so they are all that
+/// is needed besides the shares to verify and recover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commitments {
+    scheme: Scheme,
+    threshold: u8,
+    secret_len: u32,
+    /// `threshold` points per chunk, chunk after chunk, lowest power first
+    points: Vec<RistrettoPoint>,
+}
+
+/// A share of a verifiably shared secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VssShare {
+    /// The x-coordinate (evaluation point) for this share
+    pub x: u8,
+    /// The value of each chunk's polynomial at `x`
+    pub y: Vec<Scalar>,
+    /// The value of each chunk's blinding polynomial at `x`; empty for Feldman
+    pub blinding: Vec<Scalar>,
+}
+
+impl Vss {
+    /// Creates dealer parameters for the given threshold and scheme.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidThreshold` if threshold is 0.
+    pub fn new(threshold: u8, scheme: Scheme) -> Result<Self> {
+        if threshold == 0 {
+            return Err(ShamirError::InvalidThreshold);
+        }
+        Ok(Self { threshold, scheme })
+    }
+
+    /// Returns the minimum number of shares needed to recover the secret.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns the commitment scheme.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Splits a secret into `shares` shares with x-coordinates `1..=shares`,
+    /// and commits to the polynomials.
+    ///
+    /// # Errors
+    /// * `ShamirError::EmptyInput` - The secret is empty
+    /// * `ShamirError::InvalidThreshold` - `shares` is below the threshold
+    /// * `ShamirError::InvalidEncoding` - The secret is 4 GiB or longer
+    pub fn split_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        secret: &[u8],
+        shares: u8,
+        rng: &mut R,
+    ) -> Result<(Commitments, Vec<VssShare>)> {
+        if secret.is_empty() {
+            return Err(ShamirError::EmptyInput);
+        }
+        if shares < self.threshold {
+            return Err(ShamirError::InvalidThreshold);
+        }
+        let secret_len = u32::try_from(secret.len()).map_err(|_| ShamirError::InvalidEncoding)?;
+
+        let threshold = self.threshold as usize;
+        let chunks = secret.chunks(CHUNK_LEN).len();
+        let blinded = self.scheme == Scheme::Pedersen;
+        let h = pedersen_generator();
+
+        let mut coefficients = Vec::with_capacity(chunks * threshold);
+        let mut blinding = Vec::new();
+        let mut points = Vec::with_capacity(chunks * threshold);
+        for chunk in secret.chunks(CHUNK_LEN) {
+            coefficients.push(chunk_to_scalar(chunk));
+            coefficients.extend((1..threshold).map(|_| Scalar::random(rng)));
+            if blinded {
+                blinding.extend((0..threshold).map(|_| Scalar::random(rng)));
+            }
+        }
+        for (i, a) in coefficients.iter().enumerate() {
+            let point = RistrettoPoint::mul_base(a);
+            points.push(if blinded {
+                point + blinding[i] * h
+            } else {
+                point
+            });
+        }
+
+        let shares = (1..=shares)
+            .map(|x| VssShare {
+                x,
+                y: evaluate_all(&coefficients, threshold, x),
+                blinding: evaluate_all(&blinding, threshold, x),
+            })
+            .collect();
+        coefficients.fill(Scalar::ZERO);
+        blinding.fill(Scalar::ZERO);
+
+        let commitments = Commitments {
+            scheme: self.scheme,
+            threshold: self.threshold,
+            secret_len,
+            points,
+        };
+        Ok((commitments, shares))
+    }
+}
+
+impl Commitments {
+    /// Returns the commitment scheme.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Returns the minimum number of shares needed to recover the secret.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns the length of the shared secret in bytes.
+    pub fn secret_len(&self) -> usize {
+        self.secret_len as usize
+    }
+
+    fn chunks(&self) -> usize {
+        (self.secret_len as usize).div_ceil(CHUNK_LEN)
+    }
+
+    /// Checks that a share lies on the committed polynomials.
+    ///
+    /// # Errors
+    /// * `ShamirError::InvalidXCoordinate` - The share's x-coordinate is zero
+    /// * `ShamirError::InconsistentShareLength` - The share does not have one
+    ///   value per chunk of the secret, or lacks the blinding values Pedersen
+    ///   commitments need
+    /// * `ShamirError::CommitmentMismatch` - The share does not match the commitments
+    pub fn verify(&self, share: &VssShare) -> Result<()> {
+        if share.x == 0 {
+            return Err(ShamirError::InvalidXCoordinate);
+        }
+        let blinding_len = match self.scheme {
+            Scheme::Feldman => 0,
+            Scheme::Pedersen => self.chunks(),
+        };
+        if share.y.len() != self.chunks() || share.blinding.len() != blinding_len {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+
+        let h = pedersen_generator();
+        let threshold = self.threshold as usize;
+        let powers: Vec<Scalar> =
+            core::iter::successors(Some(Scalar::ONE), |p| Some(p * Scalar::from(share.x)))
+                .take(threshold)
+                .collect();
+
+        for (chunk, points) in self.points.chunks(threshold).enumerate() {
+            // y·G + r·H - sum(x^j · C_j) must be the identity
+            let mut scalars = vec![share.y[chunk]];
+            let mut bases = vec![RISTRETTO_BASEPOINT_POINT];
+            if let Some(r) = share.blinding.get(chunk) {
+                scalars.push(*r);
+                bases.push(h);
+            }
+            scalars.extend(powers.iter().map(|p| -p));
+            bases.extend_from_slice(points);
+
+            let check = RistrettoPoint::vartime_multiscalar_mul(&scalars, &bases);
+            if check != RistrettoPoint::identity() {
+                return Err(ShamirError::CommitmentMismatch(share.x));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the shares and recovers the secret from the first `threshold`
+    /// of them.
+    ///
+    /// # Errors
+    /// * `ShamirError::InsufficientShares` - Not enough shares provided
+    /// * `ShamirError::DuplicateShares` - Multiple shares with same x-coordinate
+    /// * Any error of [`Commitments::verify`] for the first share that fails
+    pub fn recover<'a, T>(&self, shares: T) -> Result<Vec<u8>>
+    where
+        T: IntoIterator<Item = &'a VssShare>,
+    {
+        let shares: Vec<&VssShare> = shares.into_iter().collect();
+        let mut seen = HashSet::new();
+        for share in &shares {
+            if !seen.insert(share.x) {
+                return Err(ShamirError::DuplicateShares(share.x));
+            }
+            self.verify(share)?;
+        }
+        if shares.len() < self.threshold as usize {
+            return Err(ShamirError::InsufficientShares {
+                required: self.threshold,
+                provided: shares.len(),
+            });
+        }
+
+        let basis = &shares[..self.threshold as usize];
+        let weights = lagrange_weights_at_zero(basis);
+        let mut secret = Vec::with_capacity(self.secret_len());
+        for chunk in 0..self.chunks() {
+            let value: Scalar = basis
+                .iter()
+                .zip(&weights)
+                .map(|(share, weight)| share.y[chunk] * weight)
+                .sum();
+            let bytes = value.to_bytes();
+            let len = CHUNK_LEN.min(self.secret_len() - secret.len());
+            secret.extend_from_slice(&bytes[..len]);
+        }
+
+        Ok(secret)
+    }
+
+    /// Serializes the commitments.
+    ///
+    /// The format is: [scheme, threshold, secret_len (u32, big-endian),
+    /// compressed points...], with `threshold` points per 31-byte chunk of the
+    /// secret.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(6 + self.points.len() * ELEMENT_LEN);
+        bytes.push(self.scheme.to_byte());
+        bytes.push(self.threshold);
+        bytes.extend_from_slice(&self.secret_len.to_be_bytes());
+        for point in &self.points {
+            bytes.extend_from_slice(point.compress().as_bytes());
+        }
+        bytes
+    }
+
+    /// Deserializes commitments written by [`Commitments::to_bytes`].
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidEncoding` if the bytes are malformed or a
+    /// point is not a valid Ristretto255 encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 6 {
+            return Err(ShamirError::InvalidEncoding);
+        }
+        let scheme = Scheme::from_byte(bytes[0])?;
+        let threshold = bytes[1];
+        let secret_len = u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]);
+
+        let count = (secret_len as usize).div_ceil(CHUNK_LEN) * threshold as usize;
+        let body = &bytes[6..];
+        if threshold == 0 || secret_len == 0 || body.len() != count * ELEMENT_LEN {
+            return Err(ShamirError::InvalidEncoding);
+        }
+
+        let points = body
+            .chunks(ELEMENT_LEN)
+            .map(|chunk| {
+                CompressedRistretto::from_slice(chunk)
+                    .ok()
+                    .and_then(|compressed| compressed.decompress())
+                    .ok_or(ShamirError::InvalidEncoding)
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            scheme,
+            threshold,
+            secret_len,
+            points,
+        })
+    }
+}
+
+impl VssShare {
+    /// Serializes this share.
+    ///
+    /// The format is: [x, y scalars..., blinding scalars...], 32 bytes per
+    /// scalar in little-endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + (self.y.len() + self.blinding.len()) * ELEMENT_LEN);
+        bytes.push(self.x);
+        for scalar in self.y.iter().chain(&self.blinding) {
+            bytes.extend_from_slice(scalar.as_bytes());
+        }
+        bytes
+    }
+
+    /// Deserializes a share written by [`VssShare::to_bytes`]. The scheme
+    /// tells whether the second half of the scalars are blinding values.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidEncoding` if the length does not fit the
+    /// scheme or a scalar is not canonical.
+    pub fn from_bytes(bytes: &[u8], scheme: Scheme) -> Result<Self> {
+        let (&x, body) = bytes.split_first().ok_or(ShamirError::InvalidEncoding)?;
+        let per_chunk = match scheme {
+            Scheme::Feldman => ELEMENT_LEN,
+            Scheme::Pedersen => 2 * ELEMENT_LEN,
+        };
+        if body.is_empty() || !body.len().is_multiple_of(per_chunk) {
+            return Err(ShamirError::InvalidEncoding);
+        }
+
+        let mut scalars = body
+            .chunks(ELEMENT_LEN)
+            .map(|chunk| {
+                let mut bytes = [0u8; ELEMENT_LEN];
+                bytes.copy_from_slice(chunk);
+                Option::from(Scalar::from_canonical_bytes(bytes))
+                    .ok_or(ShamirError::InvalidEncoding)
+            })
+            .collect::<Result<Vec<Scalar>>>()?;
+        let blinding = scalars.split_off(body.len() / per_chunk);
+
+        Ok(Self {
+            x,
+            y: scalars,
+            blinding,
+        })
+    }
+}
+
+/// The Pedersen generator `H`, hashed to the group so that its discrete
+/// logarithm with respect to `G` is unknown.
+fn pedersen_generator() -> RistrettoPoint {
+    RistrettoPoint::hash_from_bytes::<Sha512>(PEDERSEN_DOMAIN)
+}
+
+/// Reads up to 31 bytes as a little-endian scalar.
+fn chunk_to_scalar(chunk: &[u8]) -> Scalar {
+    let mut bytes = [0u8; ELEMENT_LEN];
+    bytes[..chunk.len()].copy_from_slice(chunk);
+    Scalar::from_bytes_mod_order(bytes)
+}
+
+/// Evaluates each run of `threshold` coefficients, lowest power first, at `x`.
+fn evaluate_all(coefficients: &[Scalar], threshold: usize, x: u8) -> Vec<Scalar> {
+    let x = Scalar::from(x);
+    coefficients
+        .chunks(threshold)
+        .map(|poly| poly.iter().rev().fold(Scalar::ZERO, |acc, a| acc * x + a))
+        .collect()
+}
+
+/// Lagrange basis weights for interpolating at zero from the shares' x-coordinates.
+fn lagrange_weights_at_zero(shares: &[&VssShare]) -> Vec<Scalar> {
+    shares
+        .iter()
+        .map(|share| {
+            let xi = Scalar::from(share.x);
+            let (numerator, denominator) = shares
+                .iter()
+                .filter(|other| other.x != share.x)
+                .map(|other| Scalar::from(other.x))
+                .fold((Scalar::ONE, Scalar::ONE), |(num, den), xj| {
+                    (num * xj, den * (xj - xi))
+                });
+            numerator * denominator.invert()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::rand_core::SeedableRng;
+
+    fn split(scheme: Scheme, secret: &[u8], threshold: u8) -> (Commitments, Vec<VssShare>) {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([3; 32]);
+        Vss::new(threshold, scheme)
+            .unwrap()
+            .split_with_rng(secret, 5, &mut rng)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip_both_schemes() {
+        // Spans several chunks, with a short last one and high bytes
+        let secret: Vec<u8> = (0..100).map(|i| 255 - i).collect();
+        for scheme in [Scheme::Feldman, Scheme::Pedersen] {
+            let (commitments, shares) = split(scheme, &secret, 3);
+            for share in &shares {
+                assert_eq!(commitments.verify(share), Ok(()));
+            }
+            assert_eq!(commitments.recover(&shares[2..]).unwrap(), secret);
+            assert_eq!(commitments.recover(&shares[..3]).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn test_detects_bad_shares() {
+        for scheme in [Scheme::Feldman, Scheme::Pedersen] {
+            let (commitments, mut shares) = split(scheme, b"the secret", 2);
+
+            shares[1].y[0] += Scalar::ONE;
+            assert_eq!(
+                commitments.verify(&shares[1]),
+                Err(ShamirError::CommitmentMismatch(2))
+            );
+            assert_eq!(
+                commitments.recover(&shares),
+                Err(ShamirError::CommitmentMismatch(2))
+            );
+            assert_eq!(commitments.recover(&shares[2..]).unwrap(), b"the secret");
+
+            // A share moved to another x-coordinate no longer matches either
+            let mut moved = shares[0].clone();
+            moved.x = 9;
+            assert_eq!(
+                commitments.verify(&moved),
+                Err(ShamirError::CommitmentMismatch(9))
+            );
+        }
+    }
+
+    #[test]
+    fn test_pedersen_blinding_is_checked() {
+        let (commitments, mut shares) = split(Scheme::Pedersen, b"the secret", 2);
+        shares[0].blinding[0] += Scalar::ONE;
+        assert_eq!(
+            commitments.verify(&shares[0]),
+            Err(ShamirError::CommitmentMismatch(1))
+        );
+
+        shares[0].blinding.clear();
+        assert_eq!(
+            commitments.verify(&shares[0]),
+            Err(ShamirError::InconsistentShareLength)
+        );
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        for scheme in [Scheme::Feldman, Scheme::Pedersen] {
+            let (commitments, shares) = split(scheme, &[7; 40], 3);
+            assert_eq!(
+                Commitments::from_bytes(&commitments.to_bytes()).unwrap(),
+                commitments
+            );
+            for share in &shares {
+                assert_eq!(
+                    VssShare::from_bytes(&share.to_bytes(), scheme).unwrap(),
+                    *share
+                );
+            }
+        }
+
+        let (commitments, shares) = split(Scheme::Pedersen, &[7; 40], 3);
+        let bytes = commitments.to_bytes();
+        assert_eq!(
+            Commitments::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(ShamirError::InvalidEncoding)
+        );
+        assert_eq!(
+            VssShare::from_bytes(&shares[0].to_bytes(), Scheme::Feldman)
+                .map(|share| commitments.verify(&share)),
+            Ok(Err(ShamirError::InconsistentShareLength))
+        );
+        assert_eq!(
+            VssShare::from_bytes(&[0xff; 33], Scheme::Feldman),
+            Err(ShamirError::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([3; 32]);
+        assert_eq!(
+            Vss::new(0, Scheme::Feldman),
+            Err(ShamirError::InvalidThreshold)
+        );
+
+        let vss = Vss::new(3, Scheme::Feldman).unwrap();
+        assert_eq!(
+            vss.split_with_rng(b"", 5, &mut rng).err(),
+            Some(ShamirError::EmptyInput)
+        );
+        assert_eq!(
+            vss.split_with_rng(b"secret", 2, &mut rng).err(),
+            Some(ShamirError::InvalidThreshold)
+        );
+
+        let (commitments, shares) = vss.split_with_rng(b"secret", 3, &mut rng).unwrap();
+        assert_eq!(
+            commitments.recover(&shares[1..]),
+            Err(ShamirError::InsufficientShares {
+                required: 3,
+                provided: 2
+            })
+        );
+        assert_eq!(
+            commitments.recover([&shares[0], &shares[0], &shares[1]]),
+            Err(ShamirError::DuplicateShares(1))
+        );
+    }
+}